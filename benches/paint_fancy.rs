@@ -7,7 +7,7 @@ use stanza::table::{Cell, Col, Content, Row, Table};
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("build", |b| {
-        b.iter(|| build_table());
+        b.iter(build_table);
     });
 
     let table = build_table();
@@ -20,13 +20,67 @@ fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("render/markdown", |b| {
         b.iter(|| renderer.render(&table));
     });
+
+    let table = build_large_table();
+    let renderer = Console::default();
+    c.bench_function("render/console/large", |b| {
+        b.iter(|| renderer.render(&table));
+    });
+
+    let renderer = Markdown::default();
+    c.bench_function("render/markdown/large", |b| {
+        b.iter(|| renderer.render(&table));
+    });
+
+    let table = build_nested_table(6);
+    let renderer = Console::default();
+    c.bench_function("render/console/nested", |b| {
+        b.iter(|| renderer.render(&table));
+    });
+}
+
+/// A table of 1,000 rows by 8 columns, with a header row.
+fn build_large_table() -> Table {
+    let mut table = Table::default()
+        .with_cols((0..8).map(|_| Col::new(Styles::default().with(MaxWidth(20)))).collect())
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            (0..8).map(|col| format!("Column {col}").into()).collect(),
+        ));
+    for row in 0..1_000 {
+        table.push_row(Row::new(
+            Styles::default(),
+            (0..8)
+                .map(|col| format!("Cell {row}:{col} with some wrapping text").into())
+                .collect(),
+        ));
+    }
+    table
+}
+
+/// A table nested within itself to the given depth, with each level holding a 2×2 grid.
+fn build_nested_table(depth: usize) -> Table {
+    if depth == 0 {
+        return Table::default()
+            .with_row(["a", "b"])
+            .with_row(["c", "d"]);
+    }
+    Table::default()
+        .with_row(Row::new(
+            Styles::default(),
+            vec![build_nested_table(depth - 1).into(), "leaf".into()],
+        ))
+        .with_row(Row::new(
+            Styles::default(),
+            vec!["leaf".into(), build_nested_table(depth - 1).into()],
+        ))
 }
 
 fn build_table() -> Table {
     Table::with_styles(
         Styles::default()
-            .with(BorderBg(Palette16::Black))
-            .with(FillBg(Palette16::Black)),
+            .with(BorderBg(Palette16::Black.into()))
+            .with(FillBg(Palette16::Black.into())),
     )
     .with_cols(vec![
         Col::new(Styles::default().with(HAlign::Left).with(MaxWidth(40))),
@@ -54,7 +108,7 @@ fn build_table() -> Table {
         Styles::default(),
         vec![
             Cell::new(
-                Styles::default().with(TextFg(Palette16::BrightGreen)),
+                Styles::default().with(TextFg(Palette16::BrightGreen.into())),
                 "Sales".into(),
             ),
             39.into(),
@@ -63,7 +117,7 @@ fn build_table() -> Table {
             Cell::new(
                 Styles::default()
                     .with(Strikethrough(true))
-                    .with(TextFg(Palette16::BrightBlack)),
+                    .with(TextFg(Palette16::BrightBlack.into())),
                 "Walk the dog".into(),
             ),
         ],
@@ -72,7 +126,7 @@ fn build_table() -> Table {
         Styles::default(),
         vec![
             Cell::new(
-                Styles::default().with(TextFg(Palette16::BrightBlue)),
+                Styles::default().with(TextFg(Palette16::BrightBlue.into())),
                 "Engineering".into(),
             ),
             117.into(),
@@ -81,7 +135,7 @@ fn build_table() -> Table {
             Cell::new(
                 Styles::default()
                     .with(Strikethrough(true))
-                    .with(TextFg(Palette16::BrightBlack)),
+                    .with(TextFg(Palette16::BrightBlack.into())),
                 "Wash the car".into(),
             ),
         ],
@@ -90,7 +144,7 @@ fn build_table() -> Table {
         Styles::default(),
         vec![
             Cell::new(
-                Styles::default().with(TextFg(Palette16::BrightCyan)),
+                Styles::default().with(TextFg(Palette16::BrightCyan.into())),
                 Content::from("Manufacturing"),
             ),
             20.into(),
@@ -106,19 +160,19 @@ fn build_table() -> Table {
             Cell::new(
                 Styles::default()
                     .with(Bold(true))
-                    .with(TextBg(Palette16::BrightRed))
+                    .with(TextBg(Palette16::BrightRed.into()))
                     .with(Blink(true)),
                 "WARNING".into(),
             ),
             Cell::new(
                 Styles::default()
                     .with(Underline(true))
-                    .with(TextFg(Palette16::BrightYellow)),
+                    .with(TextFg(Palette16::BrightYellow.into())),
                 "Check oil temp".into(),
             ),
             Cell::new(
                 Styles::default()
-                    .with(FillBg(Palette16::BrightMagenta))
+                    .with(FillBg(Palette16::BrightMagenta.into()))
                     .with(Italic(true)),
                 Content::Computed(Box::new(|| "fill".into())), // deferred computation
             ),
@@ -132,7 +186,7 @@ fn build_table() -> Table {
             Cell::new(
                 Styles::default()
                     .with(Bold(true))
-                    .with(TextFg(Palette16::BrightRed))
+                    .with(TextFg(Palette16::BrightRed.into()))
                     .with(Blink(true)),
                 "Self destruct sequence initiated".into(),
             ),
@@ -36,9 +36,8 @@ fn main() {
     let col_width = widest_table(&examples, &renderer) + 1;
     let num_cols = (term_width - 1) / col_width;
     let mut outer_table =
-        Table::with_styles(Styles::default().with(BorderFg(Palette16::Hidden)));
+        Table::with_styles(Styles::default().with(BorderFg(Palette16::Hidden.into())));
     let cols = (0..num_cols)
-        .into_iter()
         .map(|_| Col::new(Styles::default().with(HAlign::Centred)))
         .collect();
     outer_table.set_cols(cols);
@@ -78,7 +77,6 @@ fn table_width(table: &Table, renderer: &impl Renderer) -> usize {
     let rendered = renderer.render_with_hints(table, &[RenderHint::Nested]);
     format!("{rendered}")
         .lines()
-        .into_iter()
         .map(|line| line.chars().count())
         .max()
         .unwrap_or(0)
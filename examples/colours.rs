@@ -0,0 +1,29 @@
+use stanza::renderer::console::{Console, Decor};
+use stanza::renderer::Renderer;
+use stanza::style::{Colour, ColourDepth, FillBg, HAlign, Styles};
+use stanza::table::{Cell, Row, Table};
+
+fn main() {
+    // a heatmap, shading each cell from blue to red
+    let mut table = Table::default();
+    for row in 0..4_u8 {
+        let cells = (0..8_u8)
+            .map(|col| {
+                let heat = (row * 8 + col) * 8;
+                Cell::new(
+                    Styles::default()
+                        .with(FillBg(Colour::Rgb(heat, 64, 255 - heat)))
+                        .with(HAlign::Right),
+                    heat.to_string().into(),
+                )
+            })
+            .collect();
+        table.push_row(Row::new(Styles::default(), cells));
+    }
+
+    for depth in [ColourDepth::TrueColour, ColourDepth::Palette256, ColourDepth::Palette16] {
+        println!("{depth:?}:");
+        let renderer = Console(Decor::default().with_colour_depth(depth));
+        println!("{}", renderer.render(&table));
+    }
+}
@@ -7,8 +7,8 @@ use stanza::table::{Cell, Col, Content, Row, Table};
 fn main() {
     let table = Table::with_styles(
         Styles::default()
-            .with(BorderBg(Palette16::Black))
-            .with(FillBg(Palette16::Black)),
+            .with(BorderBg(Palette16::Black.into()))
+            .with(FillBg(Palette16::Black.into())),
     )
     .with_cols(vec![
         Col::new(Styles::default().with(HAlign::Left).with(MaxWidth(40))),
@@ -36,7 +36,7 @@ fn main() {
         Styles::default(),
         vec![
             Cell::new(
-                Styles::default().with(TextFg(Palette16::BrightGreen)),
+                Styles::default().with(TextFg(Palette16::BrightGreen.into())),
                 "Sales".into(),
             ),
             39.into(),
@@ -45,7 +45,7 @@ fn main() {
             Cell::new(
                 Styles::default()
                     .with(Strikethrough(true))
-                    .with(TextFg(Palette16::BrightBlack)),
+                    .with(TextFg(Palette16::BrightBlack.into())),
                 "Walk the dog".into(),
             ),
         ],
@@ -54,7 +54,7 @@ fn main() {
         Styles::default(),
         vec![
             Cell::new(
-                Styles::default().with(TextFg(Palette16::BrightBlue)),
+                Styles::default().with(TextFg(Palette16::BrightBlue.into())),
                 "Engineering".into(),
             ),
             117.into(),
@@ -63,7 +63,7 @@ fn main() {
             Cell::new(
                 Styles::default()
                     .with(Strikethrough(true))
-                    .with(TextFg(Palette16::BrightBlack)),
+                    .with(TextFg(Palette16::BrightBlack.into())),
                 "Wash the car".into(),
             ),
         ],
@@ -72,7 +72,7 @@ fn main() {
         Styles::default(),
         vec![
             Cell::new(
-                Styles::default().with(TextFg(Palette16::BrightCyan)),
+                Styles::default().with(TextFg(Palette16::BrightCyan.into())),
                 Content::from("Manufacturing"),
             ),
             20.into(),
@@ -88,8 +88,8 @@ fn main() {
             Cell::new(
                 Styles::default()
                     .with(Bold(true))
-                    .with(TextFg(Palette16::BrightRed))
-                    .with(TextBg(Palette16::White))
+                    .with(TextFg(Palette16::BrightRed.into()))
+                    .with(TextBg(Palette16::White.into()))
                     .with(TextInvert(true))
                     .with(Blink(true)),
                 "WARNING".into(),
@@ -97,12 +97,12 @@ fn main() {
             Cell::new(
                 Styles::default()
                     .with(Underline(true))
-                    .with(TextFg(Palette16::BrightYellow)),
+                    .with(TextFg(Palette16::BrightYellow.into())),
                 "Check oil temp".into(),
             ),
             Cell::new(
                 Styles::default()
-                    .with(FillBg(Palette16::BrightMagenta))
+                    .with(FillBg(Palette16::BrightMagenta.into()))
                     .with(Italic(true)),
                 Content::Computed(Box::new(|| "fill".into())), // deferred computation
             ),
@@ -120,7 +120,7 @@ fn main() {
             Cell::new(
                 Styles::default()
                     .with(Bold(true))
-                    .with(TextFg(Palette16::BrightRed))
+                    .with(TextFg(Palette16::BrightRed.into()))
                     .with(Blink(true)),
                 "Self destruct sequence initiated".into(),
             ),
@@ -0,0 +1,33 @@
+use stanza::renderer::console::Console;
+use stanza::renderer::{Fit, RenderHint, Renderer};
+use stanza::style::{Header, MinWidth, Styles};
+use stanza::table::{Col, Row, Table};
+
+fn main() {
+    let table = Table::default()
+        .with_cols(vec![
+            Col::new(Styles::default().with(MinWidth(10))),
+            Col::default(),
+            Col::default(),
+        ])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Poem".into(), "Author".into(), "Extract".into()],
+        ))
+        .with_row(Row::from([
+            "Antigonish",
+            "William Hughes Mearns",
+            "Yesterday, upon the stair, I met a man who wasn't there! He wasn't there again today, Oh how I wish he'd go away!"
+        ]))
+        .with_row(Row::from([
+            "The Raven",
+            "Edgar Allan Poe",
+            "Ah, distinctly I remember it was in the bleak December; And each separate dying ember wrought its ghost upon the floor."
+        ]));
+
+    let renderer = Console::default();
+    for fit in [Fit::Proportional, Fit::ShrinkWidestFirst, Fit::DropRightmostCols] {
+        println!("{fit:?}:");
+        println!("{}", renderer.render_with_hints(&table, &[RenderHint::FitWidth(60, fit)]));
+    }
+}
@@ -4,11 +4,7 @@ use stanza::style::{HAlign, MinWidth, Separator, Styles};
 use stanza::table::{Col, Row, Table};
 
 fn main() {
-    let inner_renderer = Console({
-        let mut decor = Decor::default();
-        decor.draw_outer_border = false;
-        decor
-    });
+    let inner_renderer = Console(Decor::default().suppress_outer_border());
 
     let sensors = Table::default()
         .with_row(Row::from(["Water", "19.3"]))
@@ -0,0 +1,29 @@
+use stanza::renderer::console::Console;
+use stanza::renderer::Renderer;
+use stanza::style::{Header, MaxWidth, Overflow, Styles};
+use stanza::table::{Col, Row, Table};
+
+fn main() {
+    let table = Table::default()
+        .with_cols(vec![
+            Col::new(Styles::default().with(MaxWidth(19)).with(Overflow::TruncateWithEllipsis)),
+            Col::new(Styles::default().with(MaxWidth(12)).with(Overflow::TruncateMiddle)),
+            Col::new(Styles::default().with(MaxWidth(30)).with(Overflow::Truncate)),
+        ])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Timestamp".into(), "Commit".into(), "Message".into()],
+        ))
+        .with_row(Row::from([
+            "2022-11-04T10:15:30.123Z",
+            "3f7a9c1e5b2d8f04a6c9e1b7d3f5a8c2e4b6d0f1",
+            "Render each cell's content only once per render pass",
+        ]))
+        .with_row(Row::from([
+            "2022-11-04T11:42:07.456Z",
+            "9e1b7d3f5a8c2e4b6d0f13f7a9c1e5b2d8f04a6c",
+            "Fix typo",
+        ]));
+
+    println!("{}", Console::default().render(&table));
+}
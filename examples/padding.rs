@@ -0,0 +1,25 @@
+use stanza::renderer::console::Console;
+use stanza::renderer::Renderer;
+use stanza::style::{FillBg, HAlign, Header, PaddingBottom, PaddingLeft, PaddingRight, PaddingTop, Palette16, Styles};
+use stanza::table::{Col, Row, Table};
+
+fn main() {
+    let table = Table::with_styles(
+        Styles::default()
+            .with(PaddingLeft(1))
+            .with(PaddingRight(1))
+            .with(FillBg(Palette16::Blue.into())),
+    )
+    .with_cols(vec![
+        Col::default(),
+        Col::new(Styles::default().with(HAlign::Right)),
+    ])
+    .with_row(Row::new(
+        Styles::default().with(Header(true)).with(PaddingTop(1)).with(PaddingBottom(1)),
+        vec!["Department".into(), "Budget".into()],
+    ))
+    .with_row(["Sales", "90000"])
+    .with_row(["Engineering", "270000"]);
+
+    println!("{}", Console::default().render(&table));
+}
@@ -0,0 +1,48 @@
+use stanza::renderer::console::Console;
+use stanza::renderer::markdown::Markdown;
+use stanza::renderer::Renderer;
+use stanza::style::{ColSpan, HAlign, Header, RowSpan, Styles};
+use stanza::table::{Cell, Col, Row, Table};
+
+fn main() {
+    let table = Table::default()
+        .with_cols(vec![
+            Col::new(Styles::default().with(Header(true))),
+            Col::default(),
+            Col::default(),
+            Col::default(),
+        ])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec![
+                Cell::new(Styles::default().with(RowSpan(2)), "Region".into()),
+                Cell::new(
+                    Styles::default().with(ColSpan(3)).with(HAlign::Centred),
+                    "Q1".into(),
+                ),
+                "".into(),
+                "".into(),
+            ],
+        ))
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["".into(), "Jan".into(), "Feb".into(), "Mar".into()],
+        ))
+        .with_row(["North", "1200", "1350", "1100"])
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                "South".into(),
+                Cell::new(
+                    Styles::default().with(ColSpan(2)).with(RowSpan(2)),
+                    "Not reported due to a systems outage".into(),
+                ),
+                "".into(),
+                "980".into(),
+            ],
+        ))
+        .with_row(["East", "", "", "1020"]);
+
+    println!("{}", Console::default().render(&table));
+    println!("{}", Markdown::default().render(&table));
+}
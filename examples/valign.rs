@@ -0,0 +1,28 @@
+use stanza::renderer::console::Console;
+use stanza::renderer::markdown::Markdown;
+use stanza::renderer::Renderer;
+use stanza::style::{Header, MaxWidth, Styles, VAlign};
+use stanza::table::{Col, Row, Table};
+
+fn main() {
+    let table = Table::default()
+        .with_cols(vec![
+            Col::new(Styles::default().with(VAlign::Top)),
+            Col::new(Styles::default().with(VAlign::Middle)),
+            Col::new(Styles::default().with(VAlign::Bottom)),
+            Col::new(Styles::default().with(MaxWidth(20))),
+        ])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Top".into(), "Middle".into(), "Bottom".into(), "Text".into()],
+        ))
+        .with_row(Row::from([
+            "a",
+            "b",
+            "c",
+            "The quick brown fox jumps over the lazy dog",
+        ]));
+
+    println!("{}", Console::default().render(&table));
+    println!("{}", Markdown::default().render(&table));
+}
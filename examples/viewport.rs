@@ -0,0 +1,34 @@
+use stanza::renderer::console::Console;
+use stanza::renderer::{RenderHint, Renderer, Viewport};
+use stanza::style::{HAlign, Header, Styles};
+use stanza::table::{Col, Row, Table};
+
+fn main() {
+    let mut table = Table::default()
+        .with_cols(vec![
+            Col::new(Styles::default().with(Header(true)).with(HAlign::Right)),
+            Col::default(),
+            Col::new(Styles::default().with(HAlign::Right)),
+        ])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["#".into(), "Sensor".into(), "Reading".into()],
+        ));
+    for row in 1..=1_000 {
+        table.push_row(Row::new(
+            Styles::default(),
+            vec![
+                row.to_string().into(),
+                format!("sensor-{:04}", row * 7 % 1_000).into(),
+                format!("{:.2}", f64::from(row) * 1.618).into(),
+            ],
+        ));
+    }
+
+    // page through the table, five rows at a time, keeping the header row and column in view
+    let renderer = Console::default();
+    for first in [1, 6, 996] {
+        let viewport = Viewport::rows(first..first + 5);
+        println!("{}", renderer.render_with_hints(&table, &[RenderHint::Viewport(viewport)]));
+    }
+}
@@ -2,6 +2,9 @@
 
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod table;
 pub mod renderer;
 pub mod style;
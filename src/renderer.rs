@@ -1,14 +1,29 @@
-use crate::style::{HAlign, MaxWidth, MinWidth, Style};
+use crate::style::{
+    HAlign, Header, MaxWidth, MinWidth, Overflow, PaddingBottom, PaddingLeft, PaddingRight,
+    PaddingTop, Style, Styles, VAlign,
+};
 use crate::table::{Content, Table};
 use alloc::borrow::Cow;
 use alloc::format;
-use alloc::string::String;
+use alloc::string::{String, ToString};
+use alloc::vec;
 use alloc::vec::Vec;
-use core::fmt::Display;
+use core::fmt;
+use core::fmt::{Display, Write};
 use core::mem;
+use core::ops::Range;
+use width::{display_width, grapheme_width, graphemes, is_escape};
 
 pub mod console;
+pub mod csv;
+pub mod html;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod json;
+pub mod layout;
 pub mod markdown;
+pub mod pagination;
+pub mod width;
 
 pub const NEWLINE: &str = "\n";
 
@@ -23,13 +38,155 @@ pub trait Renderer {
     fn render_with_hints(&self, table: &Table, hints: &[RenderHint]) -> Self::Output;
 }
 
+/// A [`Renderer`] that can write its output incrementally, one row at a time, rather than
+/// materialising the entire output before returning it.
+///
+/// Only the output is streamed. The table is laid out in its entirety before the first row is
+/// written, as the width of each column depends on every cell within it; the memory taken up by
+/// the layout is thus proportional to the size of the table. What streaming saves is the buffer
+/// holding the rendered output, which would otherwise be of a similar size again.
+pub trait StreamRenderer: Renderer {
+    /// Writes the rendered table to the given sink, one row at a time.
+    ///
+    /// # Errors
+    /// If the sink fails to accept any of the output, in which case the output written thus far
+    /// is incomplete.
+    fn render_into(&self, table: &Table, hints: &[RenderHint], out: &mut impl Write) -> fmt::Result;
+
+    /// Writes the rendered table to the given byte sink, such as a file or the standard output.
+    ///
+    /// # Errors
+    /// If the sink fails to accept any of the output, relaying the underlying I/O error.
+    #[cfg(feature = "std")]
+    fn render_into_io(
+        &self,
+        table: &Table,
+        hints: &[RenderHint],
+        out: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        let mut adapter = io::IoAdapter::new(out);
+        let result = self.render_into(table, hints, &mut adapter);
+        adapter.into_result(result)
+    }
+}
+
 #[derive(PartialEq, Eq)]
 pub enum RenderHint {
     Nested,
+
+    /// Fits the table within the given number of columns, including borders, using the given
+    /// strategy. Renderers that don't lay out text in fixed-width columns ignore this hint.
+    FitWidth(usize, Fit),
+
+    /// Renders only the rows and columns within the given [`Viewport`]. Renderers that don't lay
+    /// out text in fixed-width columns ignore this hint.
+    Viewport(Viewport),
+
+    /// Lays out the columns at the given widths, indexed by column, rather than measuring the
+    /// content of the cells. Renderers that don't lay out text in fixed-width columns ignore this
+    /// hint.
+    ColWidths(Vec<usize>),
+
+    /// Splits a table that is wider than the given number of columns, including borders, into
+    /// bands of columns, drawn one below the other. The [`Header`] columns are repeated in every
+    /// band. Renderers that don't lay out text in fixed-width columns ignore this hint.
+    Bands(usize),
+}
+
+impl RenderHint {
+    /// Locates the first [`RenderHint::FitWidth`] hint in the given slice.
+    pub fn fit_width(hints: &[RenderHint]) -> Option<(usize, Fit)> {
+        hints.iter().find_map(|hint| match hint {
+            RenderHint::FitWidth(target, fit) => Some((*target, *fit)),
+            _ => None,
+        })
+    }
+
+    /// Locates the first [`RenderHint::ColWidths`] hint in the given slice.
+    pub fn col_widths(hints: &[RenderHint]) -> Option<&[usize]> {
+        hints.iter().find_map(|hint| match hint {
+            RenderHint::ColWidths(widths) => Some(&widths[..]),
+            _ => None,
+        })
+    }
+
+    /// Locates the first [`RenderHint::Bands`] hint in the given slice.
+    pub fn bands(hints: &[RenderHint]) -> Option<usize> {
+        hints.iter().find_map(|hint| match hint {
+            RenderHint::Bands(target) => Some(*target),
+            _ => None,
+        })
+    }
+
+    /// Locates the first [`RenderHint::Viewport`] hint in the given slice.
+    pub fn viewport(hints: &[RenderHint]) -> Option<&Viewport> {
+        hints.iter().find_map(|hint| match hint {
+            RenderHint::Viewport(viewport) => Some(viewport),
+            _ => None,
+        })
+    }
+}
+
+/// A window onto a range of rows and columns of a table. Header rows and header columns are kept
+/// in view, regardless of whether they fall within the window. A window lying beyond the last row
+/// (or column) of the table is moved back to end with it, keeping its size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Viewport {
+    pub rows: Range<usize>,
+    pub cols: Range<usize>,
+    pub widths: ViewportWidths,
+}
+
+impl Viewport {
+    pub fn new(rows: Range<usize>, cols: Range<usize>) -> Self {
+        Self {
+            rows,
+            cols,
+            widths: ViewportWidths::default(),
+        }
+    }
+
+    /// A window onto the given range of rows, spanning all columns.
+    pub fn rows(rows: Range<usize>) -> Self {
+        Self::new(rows, 0..usize::MAX)
+    }
+
+    #[must_use]
+    pub fn with_widths(mut self, widths: ViewportWidths) -> Self {
+        self.widths = widths;
+        self
+    }
+}
+
+/// Determines which cells are measured when calculating the widths of the columns in a
+/// [`Viewport`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ViewportWidths {
+    /// Measures every cell in the table, so that the column widths remain stable as the viewport
+    /// is moved.
+    #[default]
+    Table,
+
+    /// Measures only the cells within the viewport. Cheaper for large tables, as cells outside the
+    /// viewport aren't rendered at all.
+    Window,
+}
+
+/// The strategy for fitting a table that is wider than the target width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fit {
+    /// Shrinks every column in proportion to its natural width.
+    Proportional,
+
+    /// Repeatedly shrinks the widest column, leaving narrow columns intact for as long as possible.
+    ShrinkWidestFirst,
+
+    /// Drops columns from the right until the remaining columns fit, without shrinking any.
+    DropRightmostCols,
 }
 
 impl Content {
-    pub fn render<R: Renderer>(&self, renderer: &R) -> Cow<str> {
+    pub fn render<R: Renderer>(&self, renderer: &R) -> Cow<'_, str> {
         match self {
             Content::Label(s) => Cow::Borrowed(s),
             Content::Computed(f) => Cow::Owned(f()),
@@ -45,54 +202,257 @@ impl Content {
             }
         }
     }
+
+    /// Renders the content, alongside its text less any nested tables, against which conditions
+    /// are evaluated. [`Content::Computed`] content is evaluated once for both.
+    pub fn render_with_text<R: Renderer>(&self, renderer: &R) -> (Cow<'_, str>, Cow<'_, str>) {
+        match self {
+            Content::Label(s) => (Cow::Borrowed(s), Cow::Borrowed(s)),
+            Content::Computed(f) => {
+                let s = f();
+                (Cow::Owned(s.clone()), Cow::Owned(s))
+            }
+            Content::Nested(_) => (self.render(renderer), Cow::Borrowed("")),
+            Content::Composite(contents) => {
+                let (mut buf, mut text) = (String::new(), String::new());
+                for content in contents {
+                    let (part, plain) = content.render_with_text(renderer);
+                    buf.push_str(&part);
+                    text.push_str(&plain);
+                }
+                (Cow::Owned(buf), Cow::Owned(text))
+            }
+        }
+    }
 }
 
 impl Table {
+    /// Calculates the widths of all columns. Cells spanning several columns are accommodated by
+    /// widening the spanned columns as evenly as their [`MaxWidth`] constraints allow, assuming a
+    /// single-character border between adjacent columns.
     pub fn col_widths(&self, renderer: &impl Renderer) -> Vec<usize> {
-        (0..self.num_cols())
-            .into_iter()
-            .map(|col| self.col_width(col, renderer))
-            .collect()
+        layout::RenderedCells::new(self, renderer).col_widths()
     }
 
+    /// Calculates the widths of the columns, shrinking or dropping them as necessary to fit the
+    /// table within `target` columns. The `overhead` function gives the number of columns taken up
+    /// by borders for a given number of table columns.
+    ///
+    /// Columns are never shrunk below their [`MinWidth`], nor below one character, widened by the
+    /// horizontal padding of their cells. If columns are dropped, the returned vector contains the
+    /// widths of the remaining (leftmost) columns only.
+    pub fn fitted_col_widths(
+        &self,
+        renderer: &impl Renderer,
+        target: usize,
+        fit: Fit,
+        overhead: impl Fn(usize) -> usize,
+    ) -> Vec<usize> {
+        layout::RenderedCells::new(self, renderer).fitted_col_widths(target, fit, overhead)
+    }
+
+    /// Groups the columns, of the given widths, into bands that fit within `target` columns
+    /// alongside the [`Header`] columns, which are repeated in every band. The widths are
+    /// typically obtained from [`Table::col_widths`]. The `overhead` function gives the number of
+    /// columns taken up by borders for a given number of table columns.
+    ///
+    /// Each band is given by the range of columns that it spans; the header columns are drawn in
+    /// every band, irrespective of the range. A band always contains at least one column besides
+    /// the headers; a column that cannot fit in a band of its own overflows it.
+    pub fn col_bands(
+        &self,
+        widths: &[usize],
+        target: usize,
+        overhead: impl Fn(usize) -> usize,
+    ) -> Vec<Range<usize>> {
+        let (header_cols, body_cols): (Vec<_>, Vec<_>) = (0..self.num_cols())
+            .partition(|&col| Header::resolve_or_default(&self.col(col).blended_styles()).0);
+        let header_width = header_cols.iter().map(|&col| widths[col]).sum::<usize>();
+
+        let mut bands = Vec::new();
+        let mut start = 0;
+        while start < body_cols.len() {
+            let mut end = start + 1;
+            let mut width = widths[body_cols[start]];
+            while end < body_cols.len() {
+                let next_width = width + widths[body_cols[end]];
+                let cols = header_cols.len() + end + 1 - start;
+                if header_width + next_width + overhead(cols) > target {
+                    break;
+                }
+                width = next_width;
+                end += 1;
+            }
+            let range_end = body_cols.get(end).copied().unwrap_or(self.num_cols());
+            bands.push(body_cols[start]..range_end);
+            start = end;
+        }
+
+        match bands.first_mut() {
+            // the first band also takes in any header columns preceding the first body column
+            Some(first) => first.start = 0,
+
+            // a table comprising only header columns is drawn in a single band
+            None => bands.push(0..self.num_cols()),
+        }
+        bands
+    }
+
+    /// Calculates the width of a single column, disregarding any cells that span multiple columns.
+    /// As spans may cross into the column from elsewhere, the entire table is rendered; prefer
+    /// [`Table::col_widths`] when measuring several columns.
     pub fn col_width(&self, col: usize, renderer: &impl Renderer) -> usize {
-        (0..self.num_rows())
-            .into_iter()
-            .map(|row| self.cell(col, row))
-            .map(|cell| {
-                // if a cell exists at the given col/row coordinate, calculate the width from the combination
-                // of its data and the MinWidth/MaxWidth constraints
-                let styles = cell.blended_styles();
-                let min_width = MinWidth::resolve_or_default(&styles).0;
-                let max_width = MaxWidth::resolve_or_default(&styles).0;
-                let widest_line = cell
-                    .map(|cell| {
-                        cell.data()
-                            .render(renderer)
-                            .lines()
-                            .map(|line| line.chars().count())
-                            .max()
-                            .unwrap_or(0)
-                    })
-                    .unwrap_or(0);
-                usize::min(usize::max(min_width, widest_line), max_width)
-            })
-            .max()
-            .unwrap_or(0)
+        layout::RenderedCells::new(self, renderer).col_width(col)
+    }
+}
+
+fn fit_widths(
+    mut widths: Vec<usize>,
+    min_widths: &[usize],
+    target: usize,
+    fit: Fit,
+    overhead: impl Fn(usize) -> usize,
+) -> Vec<usize> {
+    match fit {
+        Fit::Proportional => {
+            let available = target.saturating_sub(overhead(widths.len()));
+            let natural = widths.clone();
+            let floors = widths
+                .iter()
+                .zip(min_widths)
+                .map(|(&width, &min_width)| usize::min(width, min_width))
+                .collect::<Vec<_>>();
+
+            // columns that would be shrunk below their floor are pinned to it; the rest share the
+            // remaining width in proportion to their natural widths
+            let mut pinned = vec![false; widths.len()];
+            loop {
+                let pinned_width = (0..widths.len())
+                    .filter(|&col| pinned[col])
+                    .map(|col| floors[col])
+                    .sum::<usize>();
+                let flexible_natural = (0..widths.len())
+                    .filter(|&col| !pinned[col])
+                    .map(|col| natural[col])
+                    .sum::<usize>();
+                if pinned_width + flexible_natural <= available {
+                    return natural;
+                }
+                let flexible_available = available.saturating_sub(pinned_width);
+
+                let mut repinned = false;
+                for col in 0..widths.len() {
+                    if pinned[col] {
+                        widths[col] = floors[col];
+                    } else {
+                        widths[col] = (natural[col] * flexible_available)
+                            .checked_div(flexible_natural)
+                            .unwrap_or(0);
+                        if widths[col] < floors[col] {
+                            pinned[col] = true;
+                            repinned = true;
+                        }
+                    }
+                }
+                if repinned {
+                    continue;
+                }
+
+                // hand out the remainder lost to rounding, one character at a time
+                let mut remainder = flexible_available - (0..widths.len())
+                    .filter(|&col| !pinned[col])
+                    .map(|col| widths[col])
+                    .sum::<usize>();
+                for col in (0..widths.len()).cycle().take(widths.len() * 2) {
+                    if remainder == 0 {
+                        break;
+                    }
+                    if !pinned[col] && widths[col] < natural[col] {
+                        widths[col] += 1;
+                        remainder -= 1;
+                    }
+                }
+                return widths;
+            }
+        }
+        Fit::ShrinkWidestFirst => {
+            let available = target.saturating_sub(overhead(widths.len()));
+            let mut total = widths.iter().sum::<usize>();
+            while total > available {
+                let widest = (0..widths.len())
+                    .filter(|&col| widths[col] > min_widths[col])
+                    .max_by_key(|&col| (widths[col], usize::MAX - col));
+                match widest {
+                    None => break,
+                    Some(col) => {
+                        widths[col] -= 1;
+                        total -= 1;
+                    }
+                }
+            }
+            widths
+        }
+        Fit::DropRightmostCols => {
+            while widths.len() > 1 && widths.iter().sum::<usize>() + overhead(widths.len()) > target {
+                widths.pop();
+            }
+            widths
+        }
     }
 }
 
+/// Calculates the width of a cell from the combination of its rendered data and the
+/// [`MinWidth`]/[`MaxWidth`] constraints.
+fn cell_width(data: &str, styles: &Styles) -> usize {
+    let min_width = MinWidth::resolve_or_default(styles).0;
+    let max_width = MaxWidth::resolve_or_default(styles).0;
+    let widest_line = data.lines().map(display_width).max().unwrap_or(0);
+    let padding = PaddingLeft::resolve_or_default(styles).0 + PaddingRight::resolve_or_default(styles).0;
+    usize::min(usize::max(min_width, widest_line), max_width) + padding
+}
+
+/// Lays out the lines of a cell's content within the given width, surrounding them with the
+/// padding specified by the [`PaddingTop`], [`PaddingRight`], [`PaddingBottom`] and [`PaddingLeft`]
+/// styles. Each line, apart from those added for vertical padding, is padded to the full width.
+pub fn pad_cell(lines: Vec<String>, width: usize, styles: &Styles) -> Vec<String> {
+    let left = PaddingLeft::resolve_or_default(styles).0;
+    let right = PaddingRight::resolve_or_default(styles).0;
+    let top = PaddingTop::resolve_or_default(styles).0;
+    let bottom = PaddingBottom::resolve_or_default(styles).0;
+    if left == 0 && right == 0 && top == 0 && bottom == 0 {
+        return lines;
+    }
+
+    let content_width = width.saturating_sub(left + right);
+    let alignment = HAlign::resolve_or_default(styles);
+    let mut padded = Vec::with_capacity(top + lines.len() + bottom);
+    padded.resize(top, String::new());
+    for line in lines {
+        let mut buf = String::with_capacity(line.len() + left + right);
+        (0..left).for_each(|_| buf.push(' '));
+        buf.push_str(&pad(&line, ' ', content_width, &alignment));
+        (0..right).for_each(|_| buf.push(' '));
+        padded.push(buf);
+    }
+    padded.resize(padded.len() + bottom, String::new());
+    padded
+}
+
+/// The width available to the content of a cell of the given width, after deducting the horizontal
+/// padding.
+pub fn content_width(width: usize, styles: &Styles) -> usize {
+    width.saturating_sub(
+        PaddingLeft::resolve_or_default(styles).0 + PaddingRight::resolve_or_default(styles).0,
+    )
+}
+
 pub fn pad<'a>(s: &'a str, p: char, width: usize, alignment: &HAlign) -> Cow<'a, str> {
-    let chars = s.chars().collect::<Vec<_>>();
-    if chars.len() >= width {
+    let consumed = display_width(s);
+    if consumed >= width {
         Cow::Borrowed(s)
     } else {
-        let mut buf = String::with_capacity(width);
-        let mut consumed = 0;
-        for ch in chars {
-            buf.push(ch);
-            consumed += 1;
-        }
+        let mut buf = String::with_capacity(s.len() + width - consumed);
+        buf.push_str(s);
         match alignment {
             HAlign::Left => {
                 for _ in consumed..width {
@@ -118,66 +478,157 @@ pub fn pad<'a>(s: &'a str, p: char, width: usize, alignment: &HAlign) -> Cow<'a,
     }
 }
 
+/// The number of blank lines that should precede `lines` lines of content so that the content is
+/// aligned within `height` lines.
+pub fn vertical_offset(lines: usize, height: usize, alignment: &VAlign) -> usize {
+    let excess = height.saturating_sub(lines);
+    match alignment {
+        VAlign::Top => 0,
+        VAlign::Middle => excess / 2,
+        VAlign::Bottom => excess,
+    }
+}
+
+/// Lays out the given string in lines no wider than `width` columns, either by wrapping or
+/// truncating it, as determined by the `overflow` mode. Explicit line breaks are always honoured.
+pub fn fit_text(s: &str, width: usize, overflow: &Overflow) -> Vec<String> {
+    if *overflow == Overflow::Wrap {
+        wrap(s, width)
+    } else {
+        let mut lines = s
+            .lines()
+            .map(|line| truncate(line, width, overflow).into_owned())
+            .collect::<Vec<_>>();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines
+    }
+}
+
+pub const ELLIPSIS: &str = "…";
+
+/// The marker appended to the content of a cell to reference the footnote of the given number,
+/// written in superscript digits.
+pub fn footnote_marker(number: usize) -> String {
+    const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    number
+        .to_string()
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .map(|digit| SUPERSCRIPTS[digit as usize])
+        .collect()
+}
+
+/// Truncates a single line of text to at most `width` columns. The ellipsis, where applicable,
+/// counts towards the width. Embedded escape sequences are retained, even if the text they
+/// surround is cut off.
+pub fn truncate<'a>(line: &'a str, width: usize, overflow: &Overflow) -> Cow<'a, str> {
+    let line_width = display_width(line);
+    if line_width <= width {
+        return Cow::Borrowed(line);
+    }
+
+    let available = width.saturating_sub(display_width(ELLIPSIS));
+    let (ellipsis, head, tail) = match overflow {
+        Overflow::Wrap | Overflow::Truncate => ("", width, 0),
+        Overflow::TruncateWithEllipsis if width > 0 => (ELLIPSIS, available, 0),
+        Overflow::TruncateMiddle if width > 0 => (ELLIPSIS, available - available / 2, available / 2),
+        Overflow::TruncateWithEllipsis | Overflow::TruncateMiddle => ("", 0, 0),
+    };
+    let tail_start = line_width - tail;
+
+    let mut buf = String::with_capacity(line.len());
+    let mut consumed = 0;
+    let mut elided = false;
+    for grapheme in graphemes(line) {
+        let grapheme_width = grapheme_width(grapheme);
+        if is_escape(grapheme) || consumed + grapheme_width <= head || consumed >= tail_start {
+            buf.push_str(grapheme);
+        } else if !elided {
+            buf.push_str(ellipsis);
+            elided = true;
+        }
+        consumed += grapheme_width;
+    }
+    Cow::Owned(buf)
+}
+
+/// Wraps the given string into lines no wider than `width` columns, breaking between words where
+/// possible. A word that is too wide to fit on a line on its own is broken between graphemes. A
+/// grapheme is never split; one that alone is wider than `width` (such as a wide glyph in a column
+/// one character wide) is replaced with an ellipsis.
+///
+/// Embedded ANSI escape sequences occupy no width. Where SGR (Select Graphic Rendition) attributes
+/// are active at the end of a line, the line is terminated with a reset and the attributes are
+/// reinstated at the start of the next line, so that each line may be printed in isolation.
 pub fn wrap(s: &str, width: usize) -> Vec<String> {
     let mut wrapped_lines = Vec::new();
     let mut wrapped_lines_before;
+    let mut sgr = String::new();
     for input_line in s.lines() {
         wrapped_lines_before = wrapped_lines.len();
-        let mut buf_chars = 0;
-        let mut buf = String::new();
+        let mut buf_width = 0;
+        let mut buf = sgr.clone();
         for word in split_preserving_whitespace(input_line) {
-            let chars = word.chars();
-            let word_chars = chars.count();
-            let needed_width = if buf_chars > 0 {
-                word_chars + 1
+            let word_width = display_width(&word);
+            let needed_width = if buf_width > 0 {
+                word_width + 1
             } else {
-                word_chars
+                word_width
             };
-            if word_chars > width {
+            if word_width > width {
                 // too big to fit on one line
-                if buf_chars > 0 && buf_chars < width {
+                if buf_width > 0 && buf_width < width {
                     // add a space between words
-                    buf_chars += 1;
-                    if buf_chars < width {
+                    buf_width += 1;
+                    if buf_width < width {
                         // don't add a space if it'll end up being the last character on the line
                         buf.push(' ');
                     }
                 }
 
-                let chars = word.chars();
-                for ch in chars {
-                    if buf_chars == width {
-                        let current_buf = mem::take(&mut buf);
-                        wrapped_lines.push(current_buf);
-                        buf_chars = 0;
+                for grapheme in graphemes(&word) {
+                    let (grapheme, grapheme_width) = match grapheme_width(grapheme) {
+                        grapheme_width if grapheme_width <= width => (grapheme, grapheme_width),
+                        _ if width > 0 => (ELLIPSIS, display_width(ELLIPSIS)),
+                        _ => ("", 0),
+                    };
+                    if buf_width > 0 && buf_width + grapheme_width > width {
+                        let current_buf = mem::replace(&mut buf, sgr.clone());
+                        wrapped_lines.push(end_line(current_buf, &sgr));
+                        buf_width = 0;
                     }
 
-                    buf.push(ch);
-                    buf_chars += 1;
+                    buf.push_str(grapheme);
+                    buf_width += grapheme_width;
+                    track_sgr(&mut sgr, grapheme);
                 }
-            } else if buf_chars + needed_width > width {
+            } else if buf_width + needed_width > width {
                 // can fit on one line but too big to fit on this line
-                let current_buf = mem::take(&mut buf);
-                wrapped_lines.push(current_buf);
-                buf_chars = word_chars;
+                let current_buf = mem::replace(&mut buf, sgr.clone());
+                wrapped_lines.push(end_line(current_buf, &sgr));
+                buf_width = word_width;
                 buf.push_str(&word);
+                track_sgr(&mut sgr, &word);
             } else {
                 // can fit on this line
-                if buf_chars > 0 {
+                if buf_width > 0 {
                     // add a space between words
-                    buf_chars += 1;
+                    buf_width += 1;
                     buf.push(' ');
                 }
                 buf.push_str(&word);
-                buf_chars += word_chars;
+                buf_width += word_width;
+                track_sgr(&mut sgr, &word);
             }
         }
 
-        if wrapped_lines.len() == wrapped_lines_before || buf_chars > 0 {
+        if wrapped_lines.len() == wrapped_lines_before || buf_width > 0 {
             // always add a wrapped line if it is either nonempty (i.e., we've accumulated
             // characters in the buffer but haven't flushed it yet)  or we haven't wrapped at least
             // one line as part of this input line
-            wrapped_lines.push(buf);
+            wrapped_lines.push(end_line(buf, &sgr));
         }
     }
 
@@ -189,6 +640,38 @@ pub fn wrap(s: &str, width: usize) -> Vec<String> {
     wrapped_lines
 }
 
+const SGR_RESET: &str = "\x1b[0m";
+
+/// Accumulates the SGR sequences in the given fragment, clearing the accumulated state upon
+/// encountering a reset.
+fn track_sgr(sgr: &mut String, fragment: &str) {
+    for grapheme in graphemes(fragment).filter(|&grapheme| is_escape(grapheme)) {
+        if let Some(params) = grapheme
+            .strip_prefix("\x1b[")
+            .and_then(|grapheme| grapheme.strip_suffix('m'))
+        {
+            let first_param = params.split(';').next().unwrap_or("");
+            if first_param.is_empty() || first_param.chars().all(|ch| ch == '0') {
+                sgr.clear();
+                if params.len() > first_param.len() {
+                    // a reset followed by further attributes
+                    sgr.push_str(grapheme);
+                }
+            } else {
+                sgr.push_str(grapheme);
+            }
+        }
+    }
+}
+
+/// Terminates a wrapped line, resetting the SGR attributes if any are active.
+fn end_line(mut line: String, sgr: &str) -> String {
+    if !sgr.is_empty() {
+        line.push_str(SGR_RESET);
+    }
+    line
+}
+
 /// Splits a string slice by whitespace, while preserving extraneous whitespace that
 /// appears after the first encountered separator.
 ///
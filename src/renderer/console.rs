@@ -1,9 +1,26 @@
-use crate::renderer::{pad, wrap, RenderHint, Renderer, NEWLINE};
-use crate::style::{Blink, Bold, BorderBg, BorderFg, FillBg, FillInvert, HAlign, Header, Italic, Palette16, Separator, Strikethrough, Style, Styled, Styles, TextBg, TextFg, TextInvert, Underline};
-use crate::table::Table;
+use crate::renderer::layout::{Geometry, Layout};
+use crate::renderer::pagination::Paginate;
+use crate::renderer::width::display_width;
+use crate::renderer::{
+    footnote_marker, pad, truncate, RenderHint, Renderer, StreamRenderer, Viewport, NEWLINE,
+};
+use crate::style::{
+    Blink, Bold, BorderBg, BorderFg, Colour, ColourDepth, FillBg, FillInvert, HAlign, Italic,
+    Overflow, PaddingBottom, PaddingLeft, PaddingRight, PaddingTop, Palette16, Strikethrough, Style,
+    Styled, Styles, TextBg, TextFg, TextInvert, Underline,
+};
+use crate::table::{Caption, Content, Element, Footnote, Table};
 use alloc::borrow::Cow;
+use alloc::format;
 use alloc::string::String;
+use alloc::vec;
 use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt;
+use core::fmt::Write;
+use core::ptr;
+
+pub use crate::renderer::layout::Line;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Decor {
@@ -41,10 +58,16 @@ pub struct Decor {
     pub down_thin: char,
     pub left_thin: char,
     pub print_escape_codes: bool,
+    pub colour_depth: ColourDepth,
     pub draw_outer_border: bool,
     pub draw_inner_horizontal_border: bool,
+    pub draw_header_border: bool,
     pub remap_thin_to: Line,
     pub remap_bold_to: Line,
+    pub outer_stroke: Stroke,
+    pub inner_stroke: Stroke,
+    pub header_stroke: Stroke,
+    pub merge_nested_borders: bool,
 }
 
 impl Default for Decor {
@@ -54,6 +77,61 @@ impl Default for Decor {
 }
 
 impl Decor {
+    /// Derives the glyphs from the strokes of the outer border, the inner lines separating the
+    /// cells, and the borders of the header rows and columns. Where no glyph joins two strokes,
+    /// the nearest one is used. Where any stroke is ASCII, so are the others.
+    pub fn from_strokes(outer: Stroke, inner: Stroke, header: Stroke) -> Self {
+        let [outer, inner, header] = if [outer, inner, header].contains(&Stroke::Ascii) {
+            [Stroke::Ascii; 3]
+        } else {
+            [outer, inner, header]
+        };
+        let glyph = |up: Line, right: Line, down: Line, left: Line| {
+            stroke_glyph([up, right, down, left].map(|line| line_stroke(line, outer, inner)))
+        };
+
+        // the line styles of the arms, in the order up, right, down, left
+        let (o, t, b) = (Line::None, Line::Thin, Line::Bold);
+        Self {
+            up_bold_down_bold: glyph(b, o, b, o),
+            right_bold_left_bold: glyph(o, b, o, b),
+            right_bold_down_bold: glyph(o, b, b, o),
+            down_bold_left_bold: glyph(o, o, b, b),
+            up_bold_right_bold: glyph(b, b, o, o),
+            up_bold_left_bold: glyph(b, o, o, b),
+            up_bold_right_thin_down_bold: glyph(b, t, b, o),
+            up_bold_right_bold_down_bold: glyph(b, b, b, o),
+            up_bold_down_bold_left_thin: glyph(b, o, b, t),
+            up_bold_down_bold_left_bold: glyph(b, o, b, b),
+            right_bold_down_thin_left_bold: glyph(o, b, t, b),
+            right_bold_down_bold_left_bold: glyph(o, b, b, b),
+            up_thin_right_bold_left_bold: glyph(t, b, o, b),
+            up_bold_right_bold_left_bold: glyph(b, b, o, b),
+            up_thin_right_bold_down_thin_left_bold: glyph(t, b, t, b),
+            up_bold_right_thin_down_bold_left_thin: glyph(b, t, b, t),
+            up_bold_right_bold_down_bold_left_bold: glyph(b, b, b, b),
+            right_thin_left_thin: glyph(o, t, o, t),
+            up_thin_down_thin: glyph(t, o, t, o),
+            right_thin_down_thin: glyph(o, t, t, o),
+            down_thin_left_thin: glyph(o, o, t, t),
+            up_thin_right_thin: glyph(t, t, o, o),
+            up_thin_left_thin: glyph(t, o, o, t),
+            up_thin_right_thin_down_thin: glyph(t, t, t, o),
+            up_thin_down_thin_left_thin: glyph(t, o, t, t),
+            right_thin_down_thin_left_thin: glyph(o, t, t, t),
+            up_thin_right_thin_left_thin: glyph(t, t, o, t),
+            up_thin_right_thin_down_thin_left_thin: glyph(t, t, t, t),
+            up_thin: glyph(t, o, o, o),
+            right_thin: glyph(o, t, o, o),
+            down_thin: glyph(o, o, t, o),
+            left_thin: glyph(o, o, o, t),
+            outer_stroke: outer,
+            inner_stroke: inner,
+            header_stroke: header,
+            ..Self::double_outline()
+        }
+    }
+
     pub fn double_outline() -> Self {
         Self {
             blank: ' ',
@@ -90,19 +168,127 @@ impl Decor {
             down_thin: '╷',
             left_thin: '╴',
             print_escape_codes: true,
+            colour_depth: ColourDepth::default(),
             draw_outer_border: true,
             draw_inner_horizontal_border: true,
+            draw_header_border: false,
             remap_thin_to: Line::Thin,
-            remap_bold_to: Line::Bold
+            remap_bold_to: Line::Bold,
+            outer_stroke: Stroke::Double,
+            inner_stroke: Stroke::Single,
+            header_stroke: Stroke::Double,
+            merge_nested_borders: false
         }
     }
 
+    /// Draws every line in single strokes.
+    pub fn single_line() -> Self {
+        Self::from_strokes(Stroke::Single, Stroke::Single, Stroke::Single)
+    }
+
+    /// Draws every line in single strokes, with rounded corners.
+    pub fn rounded() -> Self {
+        Self::from_strokes(Stroke::Rounded, Stroke::Rounded, Stroke::Rounded)
+    }
+
+    /// Draws the outer border and the borders of the headers in heavy strokes, and the remaining
+    /// lines in single strokes.
+    pub fn heavy_outline() -> Self {
+        Self::from_strokes(Stroke::Heavy, Stroke::Single, Stroke::Heavy)
+    }
+
+    /// Draws the outer border and the borders of the headers in single strokes, and the remaining
+    /// lines dashed.
+    pub fn dashed() -> Self {
+        Self::from_strokes(Stroke::Single, Stroke::Dashed, Stroke::Single)
+    }
+
+    /// Draws every line in ASCII characters, for consoles and log files that cannot display
+    /// box-drawing characters.
+    pub fn ascii() -> Self {
+        Self::from_strokes(Stroke::Ascii, Stroke::Ascii, Stroke::Ascii)
+    }
+
+    /// In the style of `psql`: ASCII lines between the columns and below the headers, without an
+    /// outer border.
+    pub fn compact() -> Self {
+        Self::ascii()
+            .suppress_outer_border()
+            .suppress_inner_horizontal_border()
+            .retain_header_border()
+    }
+
+    /// In the style of a Markdown table: as [`Decor::compact`], with the columns separated by `|`
+    /// on the border below the headers.
+    pub fn markdown_like() -> Self {
+        Self {
+            up_thin_right_bold_down_thin_left_bold: '|',
+            up_bold_right_bold_down_bold_left_bold: '|',
+            ..Self::compact()
+        }
+    }
+
+    /// No lines at all; the columns are separated by a blank.
+    pub fn borderless() -> Self {
+        Self::default()
+            .suppress_outer_border()
+            .suppress_inner_horizontal_border()
+            .suppress_all_lines()
+    }
+
     #[must_use]
     pub fn suppress_escape_codes(mut self) -> Self {
         self.print_escape_codes = false;
         self
     }
 
+    /// Downsamples colours that cannot be reproduced at the given depth to their nearest
+    /// equivalents.
+    #[must_use]
+    pub fn with_colour_depth(mut self, colour_depth: ColourDepth) -> Self {
+        self.colour_depth = colour_depth;
+        self
+    }
+
+    /// Configures the colour depth and the printing of escape codes to suit the terminal attached
+    /// to the standard output, as advertised by the environment:
+    ///
+    /// * `NO_COLOR`, if nonempty, disables colours, while retaining the other text attributes.
+    /// * `CLICOLOR_FORCE`, if nonempty and other than `0`, forces the printing of escape codes,
+    ///   even if the standard output is not a terminal or the terminal is dumb.
+    /// * `TERM` of `dumb` suppresses escape codes; a value containing `256color` selects the
+    ///   256-colour palette.
+    /// * `COLORTERM` of `truecolor` or `24bit` selects true colour.
+    ///
+    /// Failing all of the above, the 16-colour palette is assumed.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn detect_capabilities(self) -> Self {
+        self.with_capabilities(|name| std::env::var(name).ok(), stdout_is_terminal())
+    }
+
+    #[cfg(feature = "std")]
+    fn with_capabilities(
+        mut self,
+        var: impl Fn(&str) -> Option<String>,
+        is_terminal: bool,
+    ) -> Self {
+        let nonempty = |name| var(name).filter(|value| !value.is_empty());
+        let forced = matches!(nonempty("CLICOLOR_FORCE"), Some(value) if value != "0");
+        let term = nonempty("TERM");
+        self.print_escape_codes = forced || is_terminal && term.as_deref() != Some("dumb");
+        self.colour_depth = if nonempty("NO_COLOR").is_some() {
+            ColourDepth::Monochrome
+        } else if matches!(nonempty("COLORTERM").as_deref(), Some("truecolor" | "24bit")) {
+            ColourDepth::TrueColour
+        } else if matches!(term, Some(term) if term.contains("256color")) {
+            ColourDepth::Palette256
+        } else {
+            ColourDepth::Palette16
+        };
+        self
+    }
+
     #[must_use]
     pub fn suppress_outer_border(mut self) -> Self {
         self.draw_outer_border = false;
@@ -115,6 +301,14 @@ impl Decor {
         self
     }
 
+    /// Draws the border between the header rows and the body, even if the inner horizontal border
+    /// is otherwise suppressed.
+    #[must_use]
+    pub fn retain_header_border(mut self) -> Self {
+        self.draw_header_border = true;
+        self
+    }
+
     #[must_use]
     pub fn suppress_all_lines(mut self) -> Self {
         self.remap_thin_to = Line::None;
@@ -122,21 +316,78 @@ impl Decor {
         self
     }
 
+    /// Lays out the cells of [`Content::Nested`] tables directly inside the cells holding them,
+    /// joining their border lines to those of the parent table. A nested table is merged only if
+    /// it fits within the cell, the cell has no padding, and the table has no captions or
+    /// footnotes; otherwise, it is drawn as text, without an outer border.
+    #[must_use]
+    pub fn merge_nested_borders(mut self) -> Self {
+        self.merge_nested_borders = true;
+        self
+    }
+
+    fn geometry(&self) -> Geometry {
+        Geometry {
+            merge_spans: true,
+            outer_width: if self.draw_outer_border { 2 } else { 0 },
+            header_line: self.header_line(),
+            row_separator_lines: usize::from(self.draw_inner_horizontal_border),
+            header_separator_lines: usize::from(
+                self.draw_inner_horizontal_border || self.draw_header_border,
+            ),
+        }
+    }
+
+    /// The line around the headers, which is drawn as the outer border unless the header stroke
+    /// differs from it.
+    fn header_line(&self) -> Line {
+        if self.header_stroke == self.outer_stroke {
+            Line::Bold
+        } else if self.header_stroke == self.inner_stroke {
+            Line::Thin
+        } else {
+            match self.header_stroke {
+                Stroke::Ascii | Stroke::Single | Stroke::Rounded => Line::Single,
+                Stroke::Dashed => Line::Dashed,
+                Stroke::Heavy => Line::Heavy,
+                Stroke::Double => Line::Double,
+            }
+        }
+    }
+
     fn remap_line(&self, line: Line) -> Line {
         match line {
             Line::None => Line::None,
             Line::Thin => self.remap_thin_to,
-            Line::Bold => self.remap_bold_to
+            Line::Bold => self.remap_bold_to,
+
+            // the remaining kinds are suppressed along with all other lines
+            Line::Single | Line::Dashed | Line::Heavy | Line::Double => {
+                if self.remap_thin_to == Line::None && self.remap_bold_to == Line::None {
+                    Line::None
+                } else {
+                    line
+                }
+            }
         }
     }
 
     fn lookup(&self, up: Line, right: Line, down: Line, left: Line) -> char {
-        let up = self.remap_line(up);
-        let right = self.remap_line(right);
-        let down = self.remap_line(down);
-        let left = self.remap_line(left);
+        let arms = [up, right, down, left].map(|line| self.remap_line(line));
+        let explicit = |line: &Line| {
+            matches!(line, Line::Single | Line::Dashed | Line::Heavy | Line::Double)
+        };
+        if arms.iter().any(explicit) {
+            // there are no dedicated glyphs for the remaining kinds; they are joined with the
+            // thin and bold lines by the strokes with which each is drawn
+            stroke_glyph(arms.map(|line| line_stroke(line, self.outer_stroke, self.inner_stroke)))
+        } else {
+            let [up, right, down, left] = arms;
+            self.glyph(up, right, down, left)
+        }
+    }
 
-        // dbg!(&up, &right, &down, &left);
+    fn glyph(&self, up: Line, right: Line, down: Line, left: Line) -> char {
         match (up, right, down, left) {
             (Line::None, Line::None, Line::None, Line::None) => self.blank,
             (Line::None, Line::Bold, Line::None, Line::Bold) => self.right_bold_left_bold,
@@ -179,16 +430,163 @@ impl Decor {
             (Line::None, Line::Thin, Line::None, Line::None) => self.right_thin,
             (Line::None, Line::None, Line::Thin, Line::None) => self.down_thin,
             (Line::None, Line::None, Line::None, Line::Thin) => self.left_thin,
-            _ => unreachable!(),
+            _ => self.nearest_glyph(up, right, down, left),
         }
     }
+
+    /// Handles combinations of lines for which there is no dedicated junction, which can arise
+    /// when merged cells interrupt a bold border. Thin lines are promoted to bold where the
+    /// junction has at least two arms; a lone bold arm is demoted to thin.
+    fn nearest_glyph(&self, up: Line, right: Line, down: Line, left: Line) -> char {
+        let lines = [up, right, down, left];
+        let arms = lines.iter().filter(|&&line| line != Line::None).count();
+        let substitute = if arms >= 2 { Line::Bold } else { Line::Thin };
+        let [up, right, down, left] = lines.map(|line| {
+            if line == Line::None {
+                Line::None
+            } else {
+                substitute
+            }
+        });
+        self.glyph(up, right, down, left)
+    }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum Line {
-    None,
-    Thin,
-    Bold,
+/// Whether the standard output is attached to a terminal. `std::io::IsTerminal` would serve, but it
+/// isn't available in Rust 1.66, the minimum supported version.
+#[cfg(feature = "std")]
+fn stdout_is_terminal() -> bool {
+    #[cfg(unix)]
+    {
+        extern "C" {
+            fn isatty(fd: core::ffi::c_int) -> core::ffi::c_int;
+        }
+        // SAFETY: isatty only inspects the given file descriptor
+        unsafe { isatty(1) == 1 }
+    }
+    #[cfg(windows)]
+    {
+        #[link(name = "kernel32")]
+        extern "system" {
+            fn GetStdHandle(std_handle: u32) -> *mut core::ffi::c_void;
+            fn GetConsoleMode(console: *mut core::ffi::c_void, mode: *mut u32) -> i32;
+        }
+        // STD_OUTPUT_HANDLE, which is defined as -11
+        const STD_OUTPUT_HANDLE: u32 = 0xFFFF_FFF5;
+        let mut mode = 0;
+        let mode = core::ptr::addr_of_mut!(mode);
+        // SAFETY: GetConsoleMode fails, rather than misbehaving, given a handle other than that of
+        // a console
+        unsafe { GetConsoleMode(GetStdHandle(STD_OUTPUT_HANDLE), mode) != 0 }
+    }
+    #[cfg(not(any(unix, windows)))]
+    {
+        false
+    }
+}
+
+/// The manner in which a class of lines is drawn by a [`Decor`] derived from strokes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stroke {
+    /// `-` and `|`, joined by `+`.
+    Ascii,
+
+    /// `─` and `│`.
+    Single,
+
+    /// `─` and `│`, with rounded corners `╭╮╰╯`.
+    Rounded,
+
+    /// `┄` and `┆`, joined as single strokes.
+    Dashed,
+
+    /// `━` and `┃`.
+    Heavy,
+
+    /// `═` and `║`.
+    Double,
+}
+
+impl Stroke {
+    /// The weight of the stroke, as indexes [`BOX_GLYPHS`].
+    fn weight(self) -> usize {
+        match self {
+            Stroke::Ascii | Stroke::Single | Stroke::Rounded | Stroke::Dashed => 1,
+            Stroke::Heavy => 2,
+            Stroke::Double => 3,
+        }
+    }
+}
+
+/// Box-drawing glyphs, indexed by the weight of each arm, in the order up, right, down, left,
+/// where the weight is 0 for no arm, 1 for light, 2 for heavy and 3 for double. Unicode lacks
+/// glyphs for most combinations of double arms with others, which are substituted: heavy arms
+/// meeting double ones become double, as do light arms in line with a double arm, and a lone
+/// double arm extends into a full line.
+const BOX_GLYPHS: &str = concat!(
+    " ╴╸═╷┐┑╕╻┒┓╗║╖╗╗",
+    "╶─╾═┌┬┭╤┎┰┱╦╓╥╦╦",
+    "╺╼━═┍┮┯╤┏┲┳╦╔╦╦╦",
+    "════╒╤╤╤╔╦╦╦╔╦╦╦",
+    "╵┘┙╛│┤┥╡╽┧┪╣║╢╣╣",
+    "└┴┵╧├┼┽╪┟╁╅╬╟╫╬╬",
+    "┕┶┷╧┝┾┿╪┢╆╈╬╠╬╬╬",
+    "╘╧╧╧╞╪╪╪╠╬╬╬╠╬╬╬",
+    "╹┚┛╝╿┦┩╣┃┨┫╣║╢╣╣",
+    "┖┸┹╩┞╀╃╬┠╂╉╬╟╫╬╬",
+    "┗┺┻╩┡╄╇╬┣╊╋╬╠╬╬╬",
+    "╚╩╩╩╠╬╬╬╠╬╬╬╠╬╬╬",
+    "║╜╝╝║╢╣╣║╢╣╣║╢╣╣",
+    "╙╨╩╩╟╫╬╬╟╫╬╬╟╫╬╬",
+    "╚╩╩╩╠╬╬╬╠╬╬╬╠╬╬╬",
+    "╚╩╩╩╠╬╬╬╠╬╬╬╠╬╬╬",
+);
+
+/// The stroke with which the given line is drawn, given the strokes of the bold (outer) and thin
+/// (inner) lines. Where either is ASCII, so are the lines of every other kind.
+fn line_stroke(line: Line, bold: Stroke, thin: Stroke) -> Option<Stroke> {
+    let ascii = bold == Stroke::Ascii || thin == Stroke::Ascii;
+    match line {
+        Line::None => None,
+        Line::Thin => Some(thin),
+        Line::Bold => Some(bold),
+        _ if ascii => Some(Stroke::Ascii),
+        Line::Single => Some(Stroke::Single),
+        Line::Dashed => Some(Stroke::Dashed),
+        Line::Heavy => Some(Stroke::Heavy),
+        Line::Double => Some(Stroke::Double),
+    }
+}
+
+/// The glyph joining the given arms, in the order up, right, down, left, each drawn with the given
+/// stroke, if any.
+fn stroke_glyph(arms: [Option<Stroke>; 4]) -> char {
+    use Stroke::{Ascii, Dashed, Rounded};
+
+    let [up, right, down, left] = arms;
+    if arms.contains(&Some(Ascii)) {
+        return if up.is_none() && down.is_none() {
+            '-'
+        } else if right.is_none() && left.is_none() {
+            '|'
+        } else {
+            '+'
+        };
+    }
+    match arms {
+        [None, Some(Dashed), None, Some(Dashed)] => '┄',
+        [Some(Dashed), None, Some(Dashed), None] => '┆',
+        [None, Some(Rounded), Some(Rounded), None] => '╭',
+        [None, None, Some(Rounded), Some(Rounded)] => '╮',
+        [Some(Rounded), Some(Rounded), None, None] => '╰',
+        [Some(Rounded), None, None, Some(Rounded)] => '╯',
+        _ => {
+            let index = arms
+                .iter()
+                .fold(0, |index, arm| index * 4 + arm.map_or(0, Stroke::weight));
+            BOX_GLYPHS.chars().nth(index).unwrap()
+        }
+    }
 }
 
 #[derive(Default)]
@@ -197,292 +595,566 @@ pub struct Console(pub Decor);
 impl Renderer for Console {
     type Output = String;
 
-    #[allow(clippy::too_many_lines, clippy::similar_names)]
     fn render_with_hints(&self, table: &Table, hints: &[RenderHint]) -> Self::Output {
+        let mut buf = String::new();
+        self.render_into(table, hints, &mut buf).unwrap();
+        buf
+    }
+}
+
+impl StreamRenderer for Console {
+    fn render_into(
+        &self,
+        table: &Table,
+        hints: &[RenderHint],
+        out: &mut impl Write,
+    ) -> fmt::Result {
         assert!(!table.is_empty(), "table cannot be empty");
-        let col_widths = table.col_widths(self);
+        let merged;
+        let console = if self.0.merge_nested_borders
+            && self.0.draw_outer_border
+            && hints.contains(&RenderHint::Nested)
+        {
+            // the borders of the parent cell surround the nested table, which draws none of its own
+            merged = Console(self.0.clone().suppress_outer_border());
+            &merged
+        } else {
+            self
+        };
+        if let Some(target) = RenderHint::bands(hints) {
+            return console.render_bands(table, hints, target, out);
+        }
+        let footnotes = console.render_part(table, hints, Captions::BOTH, out)?;
+        console.write_footnotes(&footnotes, out)
+    }
+}
+
+impl Console {
+    /// Lays out and draws the table with the given captions, returning the footnotes of the cells
+    /// in view for the caller to append.
+    fn render_part(
+        &self,
+        table: &Table,
+        hints: &[RenderHint],
+        captions: Captions,
+        out: &mut impl Write,
+    ) -> Result<Vec<Footnote>, fmt::Error> {
+        let nested = NestedLayouts::default();
+        let mut layout = self.lay_out(table, hints, &nested);
+        let seams = self.join_nested(table, &mut layout, hints, (None, None), &nested);
+        self.draw(table, &layout, &seams, hints, (None, None), captions, out)?;
+        Ok(layout.footnotes().to_vec())
+    }
+
+    /// Lays out the table, retaining the layouts of the tables nested in its cells when merging
+    /// their borders.
+    fn lay_out(&self, table: &Table, hints: &[RenderHint], nested: &NestedLayouts) -> Layout {
+        if self.0.merge_nested_borders {
+            let measuring = Measuring {
+                console: self,
+                nested,
+            };
+            Layout::new(table, &measuring, hints, &self.0.geometry())
+        } else {
+            Layout::new(table, self, hints, &self.0.geometry())
+        }
+    }
+
+    /// Draws the laid out table. The borders are coloured by the table's [`BorderFg`] and
+    /// [`BorderBg`] styles, or failing those, by the `inherited` colours of the table in which it
+    /// is nested. Only the selected captions of the table are drawn, and none of its footnotes.
+    #[allow(clippy::too_many_lines, clippy::similar_names, clippy::too_many_arguments)]
+    fn draw(
+        &self,
+        table: &Table,
+        layout: &Layout,
+        seams: &Seams,
+        hints: &[RenderHint],
+        inherited: BorderColours<'_>,
+        captions: Captions,
+        out: &mut impl Write,
+    ) -> fmt::Result {
         let decor = &self.0;
-        let grid = pre_render(self, table, &col_widths);
-        let print_escape_codes = self.0.print_escape_codes && !hints.contains(&RenderHint::Nested);
-        let border_fg = BorderFg::resolve(table.styles());
-        let border_bg = BorderBg::resolve(table.styles());
+        let col_widths = layout.col_widths();
+        let escape_codes = self.escape_codes(hints);
+        let (border_fg, border_bg) = border_colours(table, inherited);
         let mut buf = String::new();
-
-        let is_header_col_pair = |col| grid.is_header_col(col) || grid.is_header_col(col + 1);
-        let is_header_row_pair = |row| grid.is_header_row(row) || grid.is_header_row(row + 1);
+        let num_cols = col_widths.len();
+        let num_rows = layout.num_rows();
+        let inner_width = col_widths.iter().sum::<usize>() + num_cols - 1;
 
         let horizontal_line = decor.lookup(Line::None, Line::Bold, Line::None, Line::Bold);
+        let title = table.title();
+        let title = Some(&title).filter(|title| captions.title && title.is_some());
         if decor.draw_outer_border {
-            // upper outside border...
-            // top-left corner
-            let top_left = decor.lookup(Line::None, Line::Bold, Line::Bold, Line::None);
-            append_border(&mut buf, top_left, border_fg, border_bg, print_escape_codes);
+            if title.is_some() && !fits_caption(inner_width + 2) {
+                // the border is too short to carry the title, so it occupies a line of its own
+                append_caption(&mut buf, title, inner_width + 2, escape_codes);
+                buf.push_str(NEWLINE);
+            }
+
+            // upper outside border, beginning with the top-left corner...
+            let mut border = vec![decor.lookup(Line::None, Line::Bold, Line::Bold, Line::None)];
             for (col, &width) in col_widths.iter().enumerate() {
                 // horizontal line
-                for _ in 0..width {
-                    append_border(
-                        &mut buf,
-                        horizontal_line,
-                        border_fg,
-                        border_bg,
-                        print_escape_codes,
-                    );
-                }
+                border.extend((0..width).map(|_| horizontal_line));
 
-                if col < col_widths.len() - 1 {
+                if col < num_cols - 1 {
                     // junction between cells
-                    let row_separator_below = grid.is_separator_row(0);
-                    let down = if is_header_col_pair(col) {
-                        Line::Bold
-                    } else if row_separator_below {
-                        Line::None
-                    } else {
-                        Line::Thin
-                    };
-                    append_border(
-                        &mut buf,
-                        decor.lookup(Line::None, Line::Bold, down, Line::Bold),
-                        border_fg,
-                        border_bg,
-                        print_escape_codes,
-                    );
+                    let down = layout.vertical_line(col, 0);
+                    border.push(decor.lookup(Line::None, Line::Bold, down, Line::Bold));
                 }
             }
-            // bottom-right corner
-            let top_right = decor.lookup(Line::None, Line::None, Line::Bold, Line::Bold);
-            append_border(
-                &mut buf,
-                top_right,
-                border_fg,
-                border_bg,
-                print_escape_codes,
-            );
+            // top-right corner
+            border.push(decor.lookup(Line::None, Line::None, Line::Bold, Line::Bold));
+
+            // junctions with the lines of nested tables
+            for x in 0..inner_width {
+                let (_, down) = seams.up_down(0, x);
+                if down != Line::None {
+                    border[1 + x] = decor.lookup(Line::None, Line::Bold, down, Line::Bold);
+                }
+            }
+
+            // the title, if any, is drawn over the border
+            append_captioned_border(&mut buf, &border, title, border_fg, border_bg, escape_codes);
+            buf.push_str(NEWLINE);
+            out.write_str(&buf)?;
+            buf.clear();
+        } else if title.is_some() {
+            // without an outer border, the title occupies a line of its own
+            append_caption(&mut buf, title, inner_width, escape_codes);
             buf.push_str(NEWLINE);
+            out.write_str(&buf)?;
+            buf.clear();
         }
 
         // table (incl. headers and body)...
-        let vertical_line = decor.lookup(Line::Bold, Line::None, Line::Bold, Line::None);
-        for row in 0..table.num_rows() {
-            let row_separator = grid.is_separator_row(row);
-            let grid_row = &grid.cells[row];
-            let max_lines = grid_row.iter().map(|cell| cell.lines.len()).max().unwrap();
-
+        for row in 0..num_rows {
             // lines comprising the row
-            for line in 0..max_lines {
+            for line in 0..layout.row_height(row) {
                 if decor.draw_outer_border {
                     // left outer vertical separator
+                    let (right, _) = seams.right_left(0, row, line);
                     append_border(
                         &mut buf,
-                        vertical_line,
+                        decor.lookup(Line::Bold, right, Line::Bold, Line::None),
                         border_fg,
                         border_bg,
-                        print_escape_codes,
+                        escape_codes,
                     );
                 }
 
-                for col in 0..col_widths.len() {
-                    let grid_cell = &grid_row[col];
-
-                    // cell data
-                    let line = grid_cell
-                        .lines
-                        .get(line)
-                        .map_or("", |line| &line[..]);
-                    let alignment = HAlign::resolve_or_default(&grid_cell.styles);
-                    let line = pad(line, ' ', col_widths[col], &alignment);
-                    append_content(&mut buf, &line, &grid_cell.styles, print_escape_codes);
+                let mut col = 0;
+                while col < num_cols {
+                    // cell data, occupying the full width of the span
+                    let (origin_col, origin_row) = layout.origin(col, row);
+                    let span_cols = layout.span_cols(origin_col, origin_row);
+                    let cell = layout.cell(origin_col, origin_row);
+                    let text = cell.line(layout.line_of(origin_row, row) + line);
+                    let alignment = HAlign::resolve_or_default(&cell.styles);
+                    let text = pad(text, ' ', layout.spanned_width(col, span_cols), &alignment);
+                    if seams.is_nested(origin_col, origin_row) {
+                        // the lines of a nested table are formatted already
+                        buf.push_str(&text);
+                    } else {
+                        append_content(&mut buf, &text, &cell.styles, escape_codes);
+                    }
+                    col += span_cols;
 
                     // vertical cell separator
-                    if col < col_widths.len() - 1 {
-                        let (up, down) = if is_header_col_pair(col) {
-                            (Line::Bold, Line::Bold)
-                        } else if row_separator {
-                            (Line::None, Line::None)
-                        } else {
-                            (Line::Thin, Line::Thin)
-                        };
+                    if col < num_cols {
+                        let up_down = layout.vertical_line(col - 1, row);
+                        let (right, left) = seams.right_left(col, row, line);
                         append_border(
                             &mut buf,
-                            decor.lookup(up, Line::None, down, Line::None),
+                            decor.lookup(up_down, right, up_down, left),
                             border_fg,
                             border_bg,
-                            print_escape_codes,
+                            escape_codes,
                         );
                     }
                 }
 
                 if decor.draw_outer_border {
                     // right outer vertical separator
+                    let (_, left) = seams.right_left(num_cols, row, line);
                     append_border(
                         &mut buf,
-                        vertical_line,
+                        decor.lookup(Line::Bold, Line::None, Line::Bold, left),
                         border_fg,
                         border_bg,
-                        print_escape_codes,
+                        escape_codes,
                     );
                 }
                 buf.push_str(NEWLINE);
             }
 
-            if decor.draw_inner_horizontal_border {
-                // border below the row
-                if row < table.num_rows() - 1 {
-                    let header_row_pair = is_header_row_pair(row);
-                    let row_separator_below = grid.is_separator_row(row + 1);
-
-                    if decor.draw_outer_border {
-                        // vertical line with possible right junction
-                        let col_separator_right = grid.is_separator_col(0);
-                        let right = if header_row_pair {
-                            Line::Bold
-                        } else if col_separator_right {
-                            Line::None
-                        } else {
-                            Line::Thin
-                        };
-                        append_border(
-                            &mut buf,
-                            decor.lookup(Line::Bold, right, Line::Bold, Line::None),
-                            border_fg,
-                            border_bg,
-                            print_escape_codes,
-                        );
-                    }
+            // border below the row, unless suppressed
+            if layout.separator_lines(row) > 0 {
+                let height = layout.row_height(row);
+                if decor.draw_outer_border {
+                    // vertical line with possible right junction
+                    let right = either(
+                        layout.horizontal_line(0, row),
+                        seams.right_left(0, row, height).0,
+                    );
+                    append_border(
+                        &mut buf,
+                        decor.lookup(Line::Bold, right, Line::Bold, Line::None),
+                        border_fg,
+                        border_bg,
+                        escape_codes,
+                    );
+                }
 
-                    // horizontal line below the cell
-                    for (col, &width) in col_widths.iter().enumerate() {
-                        let col_separator = grid.is_separator_col(col);
-                        let (right, left) = if header_row_pair {
-                            (Line::Bold, Line::Bold)
-                        } else if col_separator {
-                            (Line::None, Line::None)
+                let mut col = 0;
+                let mut x = 0;
+                while col < num_cols {
+                    if layout.is_merged_vertically(col, row) {
+                        // a cell spanning both rows; its data takes the place of the border
+                        let (origin_col, origin_row) = layout.origin(col, row);
+                        let span_cols = layout.span_cols(origin_col, origin_row);
+                        let cell = layout.cell(origin_col, origin_row);
+                        let line = cell
+                            .line(layout.line_of(origin_row, row) + layout.row_height(row));
+                        let alignment = HAlign::resolve_or_default(&cell.styles);
+                        let width = layout.spanned_width(col, span_cols);
+                        let line = pad(line, ' ', width, &alignment);
+                        if seams.is_nested(origin_col, origin_row) {
+                            buf.push_str(&line);
                         } else {
-                            (Line::Thin, Line::Thin)
-                        };
-                        let border = decor.lookup(Line::None, right, Line::None, left);
-                        for _ in 0..width {
-                            append_border(&mut buf, border, border_fg, border_bg, print_escape_codes);
+                            append_content(&mut buf, &line, &cell.styles, escape_codes);
                         }
-
-                        if col < col_widths.len() - 1 {
-                            // junction between cells
-                            let header_col_pair = is_header_col_pair(col);
-                            let col_separator_right = grid.is_separator_col(col + 1);
-                            let up = if header_col_pair {
-                                Line::Bold
-                            } else if row_separator {
-                                Line::None
-                            } else {
-                                Line::Thin
-                            };
-                            let down = if header_col_pair {
-                                Line::Bold
-                            } else if row_separator_below {
-                                Line::None
-                            } else {
-                                Line::Thin
-                            };
-                            let right = if header_row_pair {
-                                Line::Bold
-                            } else if col_separator_right {
-                                Line::None
-                            } else {
-                                Line::Thin
-                            };
-                            let left = if header_row_pair {
-                                Line::Bold
-                            } else if col_separator {
-                                Line::None
-                            } else {
-                                Line::Thin
-                            };
-                            append_border(
-                                &mut buf,
-                                decor.lookup(up, right, down, left),
-                                border_fg,
-                                border_bg,
-                                print_escape_codes,
-                            );
+                        col += span_cols;
+                        x += width;
+                    } else {
+                        // horizontal line below the cell, joined by the lines of nested tables
+                        let right_left = layout.horizontal_line(col, row);
+                        for _ in 0..col_widths[col] {
+                            let (up, down) = seams.up_down(row + 1, x);
+                            let border = decor.lookup(up, right_left, down, right_left);
+                            append_border(&mut buf, border, border_fg, border_bg, escape_codes);
+                            x += 1;
                         }
+                        col += 1;
                     }
 
-                    if decor.draw_outer_border {
-                        // vertical line with possible left junction
-                        let col_separator_left = grid.is_separator_col(col_widths.len() - 1);
-                        let left = if header_row_pair {
-                            Line::Bold
-                        } else if col_separator_left {
-                            Line::None
-                        } else {
-                            Line::Thin
-                        };
+                    if col < num_cols {
+                        // junction between cells
+                        let [up, right, down, left] = layout.junction(col - 1, row);
+                        let (seam_up, seam_down) = seams.up_down(row + 1, x);
+                        let (seam_right, seam_left) = seams.right_left(col, row, height);
                         append_border(
                             &mut buf,
-                            decor.lookup(Line::Bold, Line::None, Line::Bold, left),
+                            decor.lookup(
+                                either(up, seam_up),
+                                either(right, seam_right),
+                                either(down, seam_down),
+                                either(left, seam_left),
+                            ),
                             border_fg,
                             border_bg,
-                            print_escape_codes,
+                            escape_codes,
                         );
+                        x += 1;
                     }
-                    buf.push_str(NEWLINE);
                 }
-            }
-        }
 
-        if decor.draw_outer_border {
-            // lower outside border...
-            // bottom-left corner
-            let bottom_left = decor.lookup(Line::Bold, Line::Bold, Line::None, Line::None);
-            append_border(
-                &mut buf,
-                bottom_left,
-                border_fg,
-                border_bg,
-                print_escape_codes,
-            );
-            for (col, &width) in col_widths.iter().enumerate() {
-                // horizontal line
-                for _ in 0..width {
+                if decor.draw_outer_border {
+                    // vertical line with possible left junction
+                    let left = either(
+                        layout.horizontal_line(num_cols - 1, row),
+                        seams.right_left(num_cols, row, height).1,
+                    );
                     append_border(
                         &mut buf,
-                        horizontal_line,
+                        decor.lookup(Line::Bold, Line::None, Line::Bold, left),
                         border_fg,
                         border_bg,
-                        print_escape_codes,
+                        escape_codes,
                     );
                 }
+                buf.push_str(NEWLINE);
+            }
+
+            // the row is complete
+            out.write_str(&buf)?;
+            buf.clear();
+        }
 
-                if col < col_widths.len() - 1 {
+        let footer = table.footer();
+        let footer = Some(&footer).filter(|footer| captions.footer && footer.is_some());
+        if decor.draw_outer_border {
+            // lower outside border, beginning with the bottom-left corner...
+            let mut border = vec![decor.lookup(Line::Bold, Line::Bold, Line::None, Line::None)];
+            for (col, &width) in col_widths.iter().enumerate() {
+                // horizontal line
+                border.extend((0..width).map(|_| horizontal_line));
+
+                if col < num_cols - 1 {
                     // junction between cells
-                    let row_separator_above = grid.is_separator_row(table.num_rows() - 1);
-                    let up = if is_header_col_pair(col) {
-                        Line::Bold
-                    } else if row_separator_above {
-                        Line::None
-                    } else {
-                        Line::Thin
-                    };
-                    append_border(
-                        &mut buf,
-                        decor.lookup(up, Line::Bold, Line::None, Line::Bold),
-                        border_fg,
-                        border_bg,
-                        print_escape_codes,
-                    );
+                    let up = layout.vertical_line(col, num_rows - 1);
+                    border.push(decor.lookup(up, Line::Bold, Line::None, Line::Bold));
                 }
             }
             // bottom-right corner
-            let bottom_right = decor.lookup(Line::Bold, Line::None, Line::None, Line::Bold);
-            append_border(
-                &mut buf,
-                bottom_right,
-                border_fg,
-                border_bg,
-                print_escape_codes,
-            );
+            border.push(decor.lookup(Line::Bold, Line::None, Line::None, Line::Bold));
+
+            // junctions with the lines of nested tables
+            for x in 0..inner_width {
+                let (up, _) = seams.up_down(num_rows, x);
+                if up != Line::None {
+                    border[1 + x] = decor.lookup(up, Line::Bold, Line::None, Line::Bold);
+                }
+            }
+
+            // the footer, if any, is drawn over the border
+            append_captioned_border(&mut buf, &border, footer, border_fg, border_bg, escape_codes);
+
+            if footer.is_some() && !fits_caption(inner_width + 2) {
+                // the border is too short to carry the footer, so it occupies a line of its own
+                buf.push_str(NEWLINE);
+                append_caption(&mut buf, footer, inner_width + 2, escape_codes);
+            }
+        } else if footer.is_some() {
+            // without an outer border, the footer occupies a line of its own
+            append_caption(&mut buf, footer, inner_width, escape_codes);
+            buf.push_str(NEWLINE);
         }
 
-        buf
+        out.write_str(&buf)
+    }
+
+    /// Writes the given notes referenced by the cells, below the table as drawn.
+    fn write_footnotes(&self, footnotes: &[Footnote], out: &mut impl Write) -> fmt::Result {
+        if footnotes.is_empty() {
+            return Ok(());
+        }
+        let mut buf = String::new();
+        for footnote in footnotes {
+            // the lower outer border ends without a line break
+            if self.0.draw_outer_border {
+                buf.push_str(NEWLINE);
+            }
+            append_footnote(&mut buf, footnote);
+            if !self.0.draw_outer_border {
+                buf.push_str(NEWLINE);
+            }
+        }
+        out.write_str(&buf)
+    }
+
+    /// Whether escape codes are emitted, and at which colour depth. They are suppressed altogether
+    /// when configured so or when rendering a nested table as the content of a cell.
+    fn escape_codes(&self, hints: &[RenderHint]) -> Option<ColourDepth> {
+        (self.0.print_escape_codes && !hints.contains(&RenderHint::Nested))
+            .then_some(self.0.colour_depth)
+    }
+
+    /// Lays out the tables nested in the cells of the given layout, when merging nested borders,
+    /// substituting the lines of each for the content of its cell. Returns the seams along which
+    /// their border lines meet those of the layout.
+    ///
+    /// Each nested table is drawn with the escape codes of its own cells and borders, its borders
+    /// inheriting the colours of the table's where it assigns none of its own.
+    fn join_nested(
+        &self,
+        table: &Table,
+        layout: &mut Layout,
+        hints: &[RenderHint],
+        inherited: BorderColours<'_>,
+        nested: &NestedLayouts,
+    ) -> Seams {
+        if !self.0.merge_nested_borders {
+            return Seams::default();
+        }
+
+        let escape_codes = self.escape_codes(hints);
+        let inherited = border_colours(table, inherited);
+
+        // the nested tables emit escape codes only if the table does
+        let child_hints: &[RenderHint] = if escape_codes.is_some() {
+            &[]
+        } else {
+            &[RenderHint::Nested]
+        };
+        let child_console = Console(self.0.clone().suppress_outer_border());
+        let spans = table.spans();
+        let mut seams = Seams::new(layout);
+        for row in 0..layout.num_rows() {
+            for col in 0..layout.num_cols() {
+                let (table_col, table_row) = (layout.table_col(col), layout.table_row(row));
+                if layout.origin(col, row) != (col, row)
+                    || spans.origin(table_col, table_row) != (table_col, table_row)
+                {
+                    continue;
+                }
+                let Some(cell) = *table.cell(table_col, table_row) else {
+                    continue;
+                };
+                let Content::Nested(child) = cell.data() else {
+                    continue;
+                };
+                if !joinable(child, &layout.cell(col, row).styles) {
+                    continue;
+                }
+
+                let span_cols = layout.span_cols(col, row);
+                let span_rows = layout.span_rows(col, row);
+                let width = layout.spanned_width(col, span_cols);
+                let last_row = row + span_rows - 1;
+                let height = layout.line_of(row, last_row) + layout.row_height(last_row);
+                let mut child_layout = nested.get(child);
+                let child_cols = child_layout.num_cols();
+                let child_rows = child_layout.num_rows();
+                let child_width = child_layout.spanned_width(0, child_cols);
+                let child_height = child_layout.line_of(0, child_rows - 1)
+                    + child_layout.row_height(child_rows - 1);
+                if child_width > width || child_height > height {
+                    continue;
+                }
+
+                // the rightmost column of the nested table extends to the edge of the cell
+                child_layout.widen(width - child_width);
+
+                // the cells of the nested table are formatted as the cell holding it, unless they
+                // assign formatting of their own
+                let text_styles = text_styles(&layout.cell(col, row).styles);
+                for r in 0..child_rows {
+                    for c in 0..child_cols {
+                        let child_cell = child_layout.cell_mut(c, r);
+                        child_cell.styles = text_styles.clone().with_all(&child_cell.styles);
+                    }
+                }
+                let child_seams = child_console.join_nested(
+                    child,
+                    &mut child_layout,
+                    child_hints,
+                    inherited,
+                    nested,
+                );
+                let lines = child_console.draw_nested(
+                    child,
+                    &child_layout,
+                    &child_seams,
+                    child_hints,
+                    inherited,
+                    height,
+                );
+
+                let layout_cell = layout.cell_mut(col, row);
+                layout_cell.lines = lines;
+                layout_cell.offset = 0;
+                seams.join(layout, (col, row), &child_layout, &child_seams);
+            }
+        }
+        seams
+    }
+
+    /// Draws a nested table, laid out as given, as the lines of the cell holding it, extending its
+    /// vertical lines to the given height of the cell.
+    fn draw_nested(
+        &self,
+        table: &Table,
+        layout: &Layout,
+        seams: &Seams,
+        hints: &[RenderHint],
+        inherited: BorderColours<'_>,
+        height: usize,
+    ) -> Vec<String> {
+        let mut buf = String::new();
+        self.draw(table, layout, seams, hints, inherited, Captions::BOTH, &mut buf)
+            .unwrap();
+        let mut lines = buf.lines().map(String::from).collect::<Vec<_>>();
+
+        // the vertical lines of the nested table extend to the bottom of the cell
+        let escape_codes = self.escape_codes(hints);
+        let (fg, bg) = border_colours(table, inherited);
+        let (num_cols, last_row) = (layout.num_cols(), layout.num_rows() - 1);
+        let mut filler = String::new();
+        for col in 0..num_cols {
+            filler.extend((0..layout.col_widths()[col]).map(|_| ' '));
+            if col < num_cols - 1 {
+                let up_down = layout.vertical_line(col, last_row);
+                let border = self.0.lookup(up_down, Line::None, up_down, Line::None);
+                append_border(&mut filler, border, fg, bg, escape_codes);
+            }
+        }
+        lines.resize(height, filler);
+        lines
+    }
+
+    /// Draws the table in bands of columns that fit within `target` columns, one below the other,
+    /// with a blank line between consecutive bands. All bands share the same column widths. The
+    /// title is drawn above the first band and the footer below the last, followed by the notes of
+    /// the cells in any band.
+    fn render_bands(
+        &self,
+        table: &Table,
+        hints: &[RenderHint],
+        target: usize,
+        out: &mut impl Write,
+    ) -> fmt::Result {
+        let geometry = self.geometry();
+        let widths = table.col_widths(self);
+        let bands = table.col_bands(&widths, target, |cols| geometry.overhead(cols));
+        let rows = RenderHint::viewport(hints)
+            .map_or(0..usize::MAX, |viewport| viewport.rows.clone());
+        let mut buf = String::new();
+        let mut footnotes = Vec::new();
+        for (band, cols) in bands.iter().enumerate() {
+            let mut band_hints = vec![
+                RenderHint::Viewport(Viewport::new(rows.clone(), cols.clone())),
+                RenderHint::ColWidths(widths.clone()),
+            ];
+            if hints.contains(&RenderHint::Nested) {
+                band_hints.push(RenderHint::Nested);
+            }
+
+            buf.clear();
+            if band > 0 {
+                buf.push_str(NEWLINE);
+            }
+            let last = band == bands.len() - 1;
+            let captions = Captions {
+                title: band == 0,
+                footer: last,
+            };
+            footnotes.extend(self.render_part(table, &band_hints, captions, &mut buf)?);
+            if !last && !buf.ends_with(NEWLINE) {
+                buf.push_str(NEWLINE);
+            }
+            out.write_str(&buf)?;
+        }
+
+        // the cells of the header columns recur in every band, but their notes are printed once
+        footnotes.sort_by_key(|footnote| footnote.number);
+        footnotes.dedup_by_key(|footnote| footnote.number);
+        self.write_footnotes(&footnotes, out)
+    }
+}
+
+impl Paginate for Console {
+    fn geometry(&self) -> Geometry {
+        self.0.geometry()
+    }
+
+    fn footnote_lines(&self, footnote: &Footnote) -> usize {
+        usize::max(1, footnote.text.lines().count())
+    }
+
+    fn frame_lines(&self, table: &Table, layout: &Layout) -> usize {
+        if self.0.draw_outer_border {
+            // the upper and lower outer borders, with the captions drawn over them unless the
+            // borders are too short, in which case each caption occupies a line of its own
+            let outer_width = layout.spanned_width(0, layout.num_cols()) + 2;
+            let captions = usize::from(table.title().is_some())
+                + usize::from(table.footer().is_some());
+            2 + if fits_caption(outer_width) { 0 } else { captions }
+        } else {
+            usize::from(table.title().is_some()) + usize::from(table.footer().is_some())
+        }
     }
 }
 
@@ -512,6 +1184,33 @@ impl Palette16 {
     }
 }
 
+impl Colour {
+    /// Obtains a pair of ANSI escape codes in the form `(foreground, background)`, downsampling
+    /// the colour to the given depth if necessary.
+    fn escape_codes(&self, depth: ColourDepth) -> (Cow<'static, str>, Cow<'static, str>) {
+        match self.downsample(depth) {
+            // neither of these is a colour as such, and both are reproduced at every depth
+            Colour::Palette16(colour @ (Palette16::Default | Palette16::Hidden)) => {
+                let (fg, bg) = colour.escape_codes();
+                (Cow::Borrowed(fg), Cow::Borrowed(bg))
+            }
+            _ if depth == ColourDepth::Monochrome => (Cow::Borrowed(""), Cow::Borrowed("")),
+            Colour::Palette16(colour) => {
+                let (fg, bg) = colour.escape_codes();
+                (Cow::Borrowed(fg), Cow::Borrowed(bg))
+            }
+            Colour::Palette256(index) => (
+                Cow::Owned(format!("\x1b[38;5;{index}m")),
+                Cow::Owned(format!("\x1b[48;5;{index}m")),
+            ),
+            Colour::Rgb(r, g, b) => (
+                Cow::Owned(format!("\x1b[38;2;{r};{g};{b}m")),
+                Cow::Owned(format!("\x1b[48;2;{r};{g};{b}m")),
+            ),
+        }
+    }
+}
+
 mod ansi {
     pub const BOLD: &str = "\x1b[1m";
     pub const ITALIC: &str = "\x1b[3m";
@@ -523,22 +1222,266 @@ mod ansi {
     pub const RESET: &str = "\x1b[0m";
 }
 
+/// The arms with which the border lines of nested tables reach into the borders surrounding the
+/// cells that hold them, so that they may be joined.
+#[derive(Default)]
+struct Seams {
+    /// For each horizontal border, from the upper outer border to the lower, the arms reaching up
+    /// and down into it, by offset from the left of the first column.
+    horizontal: Vec<Vec<(Line, Line)>>,
+
+    /// For each vertical border, from the left outer border to the right, the arms reaching right
+    /// and left into it, by row and by line within the row. The border below the row counts as
+    /// its last line.
+    vertical: Vec<Vec<Vec<(Line, Line)>>>,
+
+    /// The cells, by column and row, holding nested tables whose lines have been substituted for
+    /// their content.
+    nested: Vec<(usize, usize)>,
+}
+
+impl Seams {
+    fn new(layout: &Layout) -> Self {
+        let inner_width = layout.spanned_width(0, layout.num_cols());
+        let rows = (0..layout.num_rows())
+            .map(|row| vec![(Line::None, Line::None); layout.row_height(row) + 1])
+            .collect::<Vec<_>>();
+        Self {
+            horizontal: vec![vec![(Line::None, Line::None); inner_width]; layout.num_rows() + 1],
+            vertical: vec![rows; layout.num_cols() + 1],
+            nested: Vec::new(),
+        }
+    }
+
+    /// Records the nested table drawn in place of the content of the given cell of the layout,
+    /// joining the lines reaching out of its edges, as laid out and joined in turn, with the
+    /// borders surrounding the cell.
+    fn join(
+        &mut self,
+        layout: &Layout,
+        (col, row): (usize, usize),
+        child_layout: &Layout,
+        child_seams: &Seams,
+    ) {
+        self.nested.push((col, row));
+        let span_cols = layout.span_cols(col, row);
+        let span_rows = layout.span_rows(col, row);
+        let width = layout.spanned_width(col, span_cols);
+        let child_cols = child_layout.num_cols();
+        let child_rows = child_layout.num_rows();
+
+        // the lines reaching out of the top and bottom edges of the nested table, both its own and
+        // those of the tables nested within it
+        let x = if col == 0 { 0 } else { layout.spanned_width(0, col) + 1 };
+        let mut c = 0;
+        let mut boundary = child_layout.col_widths()[0];
+        for offset in 0..width {
+            let (up, down) = if offset == boundary {
+                let arms = (
+                    child_layout.vertical_line(c, child_rows - 1),
+                    child_layout.vertical_line(c, 0),
+                );
+                c += 1;
+                boundary += child_layout.col_widths()[c] + 1;
+                arms
+            } else {
+                (
+                    child_seams.up_down(child_rows, offset).0,
+                    child_seams.up_down(0, offset).1,
+                )
+            };
+            self.horizontal[row][x + offset].1 = down;
+            self.horizontal[row + span_rows][x + offset].0 = up;
+        }
+
+        // likewise for the lines reaching out of the left and right edges, one for each line of
+        // the nested table, counting from the top of the cell
+        let (mut parent_row, mut parent_line) = (row, 0);
+        for r in 0..child_rows {
+            let height = child_layout.row_height(r);
+            let lines = height + child_layout.separator_lines(r);
+            for line in 0..lines {
+                let (seam_right, _) = child_seams.right_left(0, r, line);
+                let (_, seam_left) = child_seams.right_left(child_cols, r, line);
+                let (right, left) = if line == height {
+                    (
+                        either(child_layout.horizontal_line(0, r), seam_right),
+                        either(child_layout.horizontal_line(child_cols - 1, r), seam_left),
+                    )
+                } else {
+                    (seam_right, seam_left)
+                };
+                self.vertical[col][parent_row][parent_line].0 = right;
+                self.vertical[col + span_cols][parent_row][parent_line].1 = left;
+
+                parent_line += 1;
+                if parent_line == layout.row_height(parent_row) + layout.separator_lines(parent_row)
+                {
+                    parent_row += 1;
+                    parent_line = 0;
+                }
+            }
+        }
+    }
+
+    /// Whether the given cell holds a nested table, drawn in place of its content.
+    fn is_nested(&self, col: usize, row: usize) -> bool {
+        self.nested.contains(&(col, row))
+    }
+
+    /// The arms reaching up and down into the given horizontal border at the given offset.
+    fn up_down(&self, border: usize, offset: usize) -> (Line, Line) {
+        self.horizontal
+            .get(border)
+            .and_then(|arms| arms.get(offset))
+            .copied()
+            .unwrap_or((Line::None, Line::None))
+    }
+
+    /// The arms reaching right and left into the given vertical border at the given line of a row.
+    fn right_left(&self, border: usize, row: usize, line: usize) -> (Line, Line) {
+        self.vertical
+            .get(border)
+            .and_then(|rows| rows.get(row))
+            .and_then(|arms| arms.get(line))
+            .copied()
+            .unwrap_or((Line::None, Line::None))
+    }
+}
+
+/// The layouts of the tables nested in the cells of a table, and in turn of those nested in theirs,
+/// retained as each is measured, so that joining its borders with those of its cell needn't lay it
+/// out, and evaluate its computed content, a second time.
+#[derive(Default)]
+struct NestedLayouts(RefCell<Vec<(*const Table, Layout)>>);
+
+impl NestedLayouts {
+    fn insert(&self, table: &Table, layout: Layout) {
+        self.0.borrow_mut().push((table, layout));
+    }
+
+    /// A copy of the layout of the given nested table, as measured.
+    fn get(&self, table: &Table) -> Layout {
+        self.0
+            .borrow()
+            .iter()
+            .find(|(nested, _)| ptr::eq(*nested, table))
+            .map(|(_, layout)| layout.clone())
+            .expect("nested table is laid out while measuring its cell")
+    }
+}
+
+/// A console measuring the cells of a table, which retains the layout of each nested table it
+/// renders.
+struct Measuring<'a> {
+    console: &'a Console,
+    nested: &'a NestedLayouts,
+}
+
+impl Renderer for Measuring<'_> {
+    type Output = String;
+
+    fn render_with_hints(&self, table: &Table, hints: &[RenderHint]) -> Self::Output {
+        // the borders of the parent cell surround the nested table, which draws none of its own
+        let console = Console(self.console.0.clone().suppress_outer_border());
+        let mut layout = console.lay_out(table, hints, self.nested);
+        self.nested.insert(table, layout.clone());
+        let seams = console.join_nested(table, &mut layout, hints, (None, None), self.nested);
+        let mut buf = String::new();
+        console
+            .draw(table, &layout, &seams, hints, (None, None), Captions::BOTH, &mut buf)
+            .unwrap();
+        console.write_footnotes(layout.footnotes(), &mut buf).unwrap();
+        buf
+    }
+}
+
+/// Whether a nested table may be drawn in place of the content of a cell with the given styles,
+/// its borders joined with those of the cell. Tables with captions or footnotes, and cells with
+/// padding, are left as rendered.
+fn joinable(child: &Table, styles: &Styles) -> bool {
+    !child.is_empty()
+        && child.title().is_none()
+        && child.footer().is_none()
+        && child.footnotes().is_empty()
+        && PaddingLeft::resolve_or_default(styles).0 == 0
+        && PaddingRight::resolve_or_default(styles).0 == 0
+        && PaddingTop::resolve_or_default(styles).0 == 0
+        && PaddingBottom::resolve_or_default(styles).0 == 0
+}
+
+/// The styles among the given ones that format the text of a cell, as opposed to laying it out or
+/// drawing its borders.
+fn text_styles(styles: &Styles) -> Styles {
+    let ids = [
+        Blink::id(),
+        Bold::id(),
+        FillBg::id(),
+        FillInvert::id(),
+        Italic::id(),
+        Strikethrough::id(),
+        TextBg::id(),
+        TextFg::id(),
+        TextInvert::id(),
+        Underline::id(),
+    ];
+    let mut text_styles = styles.clone();
+    for (key, _) in styles {
+        if !ids.iter().any(|id| id == key) {
+            text_styles.take(key);
+        }
+    }
+    text_styles
+}
+
+/// The captions of a table that are drawn, which is both unless the table is drawn in bands, the
+/// first of which carries the title and the last the footer.
+#[derive(Clone, Copy)]
+struct Captions {
+    title: bool,
+    footer: bool,
+}
+
+impl Captions {
+    const BOTH: Self = Self { title: true, footer: true };
+}
+
+/// The colours of the borders of a table, in the order foreground, background.
+type BorderColours<'a> = (Option<&'a BorderFg>, Option<&'a BorderBg>);
+
+/// The border colours assigned by the given table, or failing those, the inherited ones.
+fn border_colours<'a>(table: &'a Table, inherited: BorderColours<'a>) -> BorderColours<'a> {
+    (
+        BorderFg::resolve(table.styles()).or(inherited.0),
+        BorderBg::resolve(table.styles()).or(inherited.1),
+    )
+}
+
+/// The given line, unless it is absent, in which case the alternative.
+fn either(line: Line, alternative: Line) -> Line {
+    if line == Line::None {
+        alternative
+    } else {
+        line
+    }
+}
+
 fn append_border(
     buf: &mut String,
     b: char,
     fg: Option<&BorderFg>,
     bg: Option<&BorderBg>,
-    print_escape_codes: bool,
+    escape_codes: Option<ColourDepth>,
 ) {
-    if print_escape_codes {
+    if let Some(depth) = escape_codes {
         match (fg, bg) {
             (None, None) => buf.push(b),
             _ => {
                 if let Some(fg) = fg {
-                    buf.push_str(fg.0.escape_codes().0);
+                    buf.push_str(&fg.0.escape_codes(depth).0);
                 }
                 if let Some(bg) = bg {
-                    buf.push_str(bg.0.escape_codes().1);
+                    buf.push_str(&bg.0.escape_codes(depth).1);
                 }
                 buf.push(b);
                 buf.push_str(ansi::RESET);
@@ -549,6 +1492,91 @@ fn append_border(
     }
 }
 
+/// Whether a caption can be drawn over a horizontal outer border of the given length, leaving room
+/// for the corners, a line character and a space on either side, and at least one character of
+/// text.
+fn fits_caption(border_len: usize) -> bool {
+    border_len > 6
+}
+
+/// Appends a horizontal outer border, given by its characters from corner to corner, drawing the
+/// caption, if any, over the line. The caption is aligned within the border, leaving at least one
+/// line character between it and either corner, and is truncated if it doesn't fit. A border that
+/// is too [short](fits_caption) is drawn without the caption.
+fn append_captioned_border(
+    buf: &mut String,
+    border: &[char],
+    caption: Option<&Element<'_, Caption>>,
+    fg: Option<&BorderFg>,
+    bg: Option<&BorderBg>,
+    escape_codes: Option<ColourDepth>,
+) {
+    // the caption is separated from the line by a space on either side
+    let text = match caption.and_then(|caption| caption.map(Caption::text)) {
+        Some(text) if fits_caption(border.len()) => {
+            truncate(text, border.len() - 6, &Overflow::TruncateWithEllipsis)
+        }
+        _ => {
+            for &b in border {
+                append_border(buf, b, fg, bg, escape_codes);
+            }
+            return;
+        }
+    };
+
+    let styles = caption.map(Element::blended_styles).unwrap_or_default();
+    let text = format!(" {text} ");
+    let text_width = display_width(&text);
+    let start = match HAlign::resolve_or_default(&styles).as_ref() {
+        HAlign::Left => 2,
+        HAlign::Centred => (border.len() - text_width) / 2,
+        HAlign::Right => border.len() - 2 - text_width,
+    };
+    for &b in &border[..start] {
+        append_border(buf, b, fg, bg, escape_codes);
+    }
+    append_content(buf, &text, &styles, escape_codes);
+    for &b in &border[start + text_width..] {
+        append_border(buf, b, fg, bg, escape_codes);
+    }
+}
+
+/// Appends the caption on a line of its own, aligned within the given width and truncated if it
+/// doesn't fit.
+fn append_caption(
+    buf: &mut String,
+    caption: Option<&Element<'_, Caption>>,
+    width: usize,
+    escape_codes: Option<ColourDepth>,
+) {
+    let Some(caption) = caption else {
+        return;
+    };
+    if let Some(text) = caption.map(Caption::text) {
+        let styles = caption.blended_styles();
+        let text = truncate(text, width, &Overflow::TruncateWithEllipsis);
+        let text = pad(&text, ' ', width, &HAlign::resolve_or_default(&styles));
+        append_content(buf, &text, &styles, escape_codes);
+    }
+}
+
+/// Appends the footnote, preceded by its marker. Subsequent lines of the note are indented to align
+/// with the first.
+fn append_footnote(buf: &mut String, footnote: &Footnote) {
+    let marker = footnote_marker(footnote.number);
+    let indent = display_width(&marker) + 1;
+    for (i, line) in footnote.text.lines().enumerate() {
+        if i == 0 {
+            buf.push_str(&marker);
+            buf.push(' ');
+        } else {
+            buf.push_str(NEWLINE);
+            (0..indent).for_each(|_| buf.push(' '));
+        }
+        buf.push_str(line);
+    }
+}
+
 fn find_first_printable(chars: impl Iterator<Item = char>) -> Option<usize> {
     chars
         .enumerate()
@@ -556,12 +1584,12 @@ fn find_first_printable(chars: impl Iterator<Item = char>) -> Option<usize> {
         .map(|(i, _)| i)
 }
 
-fn append_content(buf: &mut String, s: &str, styles: &Styles, print_escape_codes: bool) {
-    if print_escape_codes {
+fn append_content(buf: &mut String, s: &str, styles: &Styles, escape_codes: Option<ColourDepth>) {
+    if let Some(depth) = escape_codes {
         // formatting that applies to the entire line (both whitespace and printable characters)
         let mut line_format = String::new();
         if let Some(bg) = FillBg::resolve(styles) {
-            line_format.push_str(bg.0.escape_codes().1);
+            line_format.push_str(&bg.0.escape_codes(depth).1);
         }
         if FillInvert::resolve_or_default(styles).0 {
             line_format.push_str(ansi::REVERSE);
@@ -582,10 +1610,10 @@ fn append_content(buf: &mut String, s: &str, styles: &Styles, print_escape_codes
             char_format.push_str(ansi::STRIKETHROUGH);
         }
         if let Some(bg) = TextBg::resolve(styles) {
-            char_format.push_str(bg.0.escape_codes().1);
+            char_format.push_str(&bg.0.escape_codes(depth).1);
         }
         if let Some(fg) = TextFg::resolve(styles) {
-            char_format.push_str(fg.0.escape_codes().0);
+            char_format.push_str(&fg.0.escape_codes(depth).0);
         }
         if TextInvert::resolve_or_default(styles).0 {
             char_format.push_str(ansi::REVERSE);
@@ -624,66 +1652,5 @@ fn switch_format(buf: &mut String, new_format: &str, old_format: &str) {
     }
 }
 
-fn pre_render(renderer: &Console, table: &Table, col_widths: &[usize]) -> Grid {
-    let col_styles = (0..table.num_cols())
-        .into_iter()
-        .map(|col| table.col(col).blended_styles())
-        .collect::<Vec<_>>();
-
-    let row_styles = (0..table.num_rows())
-        .into_iter()
-        .map(|row| table.row(row).blended_styles())
-        .collect::<Vec<_>>();
-
-    let cells = (0..table.num_rows())
-        .into_iter()
-        .map(|row| {
-            (0..table.num_cols())
-                .into_iter()
-                .map(|col| {
-                    let cell = table.cell(col, row);
-                    let data = cell
-                        .as_ref()
-                        .map_or(Cow::Borrowed(""), |cell| cell.data().render(renderer));
-                    let lines = wrap(&data, col_widths[col]);
-                    let styles = cell.blended_styles();
-                    GridCell { lines, styles }
-                })
-                .collect()
-        })
-        .collect();
-
-    Grid {
-        cells,
-        col_styles,
-        row_styles,
-    }
-}
-
-struct Grid {
-    cells: Vec<Vec<GridCell>>,
-    col_styles: Vec<Styles>,
-    row_styles: Vec<Styles>,
-}
-
-impl Grid {
-    fn is_header_col(&self, col: usize) -> bool {
-        Header::resolve_or_default(&self.col_styles[col]).0
-    }
-
-    fn is_header_row(&self, row: usize) -> bool {
-        Header::resolve_or_default(&self.row_styles[row]).0
-    }
-    fn is_separator_col(&self, col: usize) -> bool {
-        Separator::resolve_or_default(&self.col_styles[col]).0
-    }
-
-    fn is_separator_row(&self, row: usize) -> bool {
-        Separator::resolve_or_default(&self.row_styles[row]).0
-    }
-}
-
-struct GridCell {
-    lines: Vec<String>,
-    styles: Styles,
-}
+#[cfg(test)]
+mod tests;
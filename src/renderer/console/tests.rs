@@ -0,0 +1,684 @@
+use crate::renderer::console::{Console, Decor, Stroke};
+use crate::renderer::{RenderHint, Renderer, StreamRenderer};
+use crate::style::{
+    Banding, Bold, BorderBottom, BorderFg, BorderTop, ColSpan, Colour, ColourDepth, HAlign, Header,
+    Line, MaxWidth, MinWidth, Palette16, RowSpan, Styles, TextFg,
+};
+use crate::table::{Caption, Cell, Col, Row, Table};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+fn styled_table() -> Table {
+    Table::default().with_row(Row::new(
+        Styles::default(),
+        vec![
+            Cell::new(Styles::default().with(TextFg(Colour::Rgb(255, 0, 0))), "a".into()),
+            Cell::new(
+                Styles::default().with(TextFg(Palette16::Green.into())).with(Bold(true)),
+                "b".into(),
+            ),
+        ],
+    ))
+}
+
+#[test]
+fn render_colour_depths() {
+    #[derive(Debug)]
+    struct Case {
+        input: ColourDepth,
+        expect: (&'static str, &'static str),
+    }
+
+    for case in [
+        Case {
+            input: ColourDepth::TrueColour,
+            expect: ("\x1b[38;2;255;0;0ma", "\x1b[1m\x1b[32mb"),
+        },
+        Case {
+            input: ColourDepth::Palette256,
+            expect: ("\x1b[38;5;196ma", "\x1b[1m\x1b[32mb"),
+        },
+        Case {
+            input: ColourDepth::Palette16,
+            expect: ("\x1b[31ma", "\x1b[1m\x1b[32mb"),
+        },
+        Case {
+            input: ColourDepth::Monochrome,
+            expect: ("║a", "\x1b[1mb"),
+        },
+    ] {
+        let renderer = Console(Decor::default().with_colour_depth(case.input));
+        let rendered = renderer.render(&styled_table());
+        assert!(rendered.contains(case.expect.0), "for {:?} in {:?}", &case, rendered);
+        assert!(rendered.contains(case.expect.1), "for {:?} in {:?}", &case, rendered);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn with_capabilities() {
+    #[derive(Debug)]
+    struct Case {
+        input: (&'static [(&'static str, &'static str)], bool),
+        expect: (bool, ColourDepth),
+    }
+
+    for case in [
+        Case {
+            input: (&[], true),
+            expect: (true, ColourDepth::Palette16),
+        },
+        Case {
+            input: (&[], false),
+            expect: (false, ColourDepth::Palette16),
+        },
+        Case {
+            input: (&[("TERM", "xterm-256color")], true),
+            expect: (true, ColourDepth::Palette256),
+        },
+        Case {
+            input: (&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")], true),
+            expect: (true, ColourDepth::TrueColour),
+        },
+        Case {
+            input: (&[("COLORTERM", "24bit"), ("NO_COLOR", "1")], true),
+            expect: (true, ColourDepth::Monochrome),
+        },
+        Case {
+            input: (&[("NO_COLOR", "")], true),
+            expect: (true, ColourDepth::Palette16),
+        },
+        Case {
+            input: (&[("TERM", "dumb")], true),
+            expect: (false, ColourDepth::Palette16),
+        },
+        Case {
+            input: (&[("TERM", "dumb"), ("CLICOLOR_FORCE", "1")], true),
+            expect: (true, ColourDepth::Palette16),
+        },
+        Case {
+            input: (&[("CLICOLOR_FORCE", "1")], false),
+            expect: (true, ColourDepth::Palette16),
+        },
+        Case {
+            input: (&[("CLICOLOR_FORCE", "0")], false),
+            expect: (false, ColourDepth::Palette16),
+        },
+    ] {
+        let vars = case.input.0;
+        let var = |name: &str| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value).into())
+        };
+        let decor = Decor::default().with_capabilities(var, case.input.1);
+        assert_eq!(case.expect, (decor.print_escape_codes, decor.colour_depth), "for {:?}", &case);
+    }
+}
+
+#[test]
+fn render_spans() {
+    struct Case {
+        input: Table,
+        expect: &'static str,
+    }
+
+    for case in [
+        // the spanning cell widens the spanned columns evenly
+        Case {
+            input: Table::default()
+                .with_row(Row::new(
+                    Styles::default(),
+                    vec![
+                        Cell::new(
+                            Styles::default().with(ColSpan(2)).with(HAlign::Centred),
+                            "Quarter one".into(),
+                        ),
+                        "".into(),
+                        "c".into(),
+                    ],
+                ))
+                .with_row(["a", "b", "c"]),
+            expect: "╔═══════════╤═╗\n\
+                     ║Quarter one│c║\n\
+                     ╟─────┬─────┼─╢\n\
+                     ║a    │b    │c║\n\
+                     ╚═════╧═════╧═╝",
+        },
+        // the border between the spanned rows is broken off at the spanning cell
+        Case {
+            input: Table::default()
+                .with_row(Row::new(
+                    Styles::default(),
+                    vec![
+                        Cell::new(Styles::default().with(RowSpan(2)), "x".into()),
+                        "a".into(),
+                        "b".into(),
+                    ],
+                ))
+                .with_row(["", "c", "d"])
+                .with_row(["e", "f", "g"]),
+            expect: "╔═╤═╤═╗\n\
+                     ║x│a│b║\n\
+                     ║ ├─┼─╢\n\
+                     ║ │c│d║\n\
+                     ╟─┼─┼─╢\n\
+                     ║e│f│g║\n\
+                     ╚═╧═╧═╝",
+        },
+        // the junctions around a cell spanning both columns and rows
+        Case {
+            input: Table::default()
+                .with_row(["a", "b", "c"])
+                .with_row(Row::new(
+                    Styles::default(),
+                    vec![
+                        "d".into(),
+                        Cell::new(
+                            Styles::default().with(ColSpan(2)).with(RowSpan(2)),
+                            "merged".into(),
+                        ),
+                    ],
+                ))
+                .with_row(["e", "", ""]),
+            expect: "╔═╤═══╤══╗\n\
+                     ║a│b  │c ║\n\
+                     ╟─┼───┴──╢\n\
+                     ║d│merged║\n\
+                     ╟─┤      ║\n\
+                     ║e│      ║\n\
+                     ╚═╧══════╝",
+        },
+    ] {
+        let renderer = Console(Decor::default().suppress_escape_codes());
+        assert_eq!(case.expect, renderer.render(&case.input));
+    }
+}
+
+#[test]
+fn render_into_writes_row_by_row() {
+    #[derive(Default)]
+    struct Chunks(Vec<String>);
+
+    impl fmt::Write for Chunks {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0.push(s.into());
+            Ok(())
+        }
+    }
+
+    let table = Table::default().with_row(["a", "b"]).with_row(["c", "d"]);
+    let renderer = Console(Decor::default().suppress_escape_codes());
+    let mut chunks = Chunks::default();
+    renderer.render_into(&table, &[], &mut chunks).unwrap();
+    assert_eq!(
+        vec!["╔═╤═╗\n", "║a│b║\n╟─┼─╢\n", "║c│d║\n", "╚═╧═╝"],
+        chunks.0
+    );
+    assert_eq!(renderer.render(&table), chunks.0.concat());
+}
+
+#[test]
+fn render_bands() {
+    let table = Table::default()
+        .with_cols(vec![
+            Col::new(Styles::default().with(Header(true))),
+            Col::default(),
+            Col::default(),
+            Col::default(),
+        ])
+        .with_row(["Year", "Q1", "Q2", "Q3"])
+        .with_row(["2024", "100", "120", "95"]);
+    let renderer = Console(Decor::default().suppress_escape_codes());
+    assert_eq!(
+        "╔════╦═══╤═══╗\n║Year║Q1 │Q2 ║\n╟────╫───┼───╢\n║2024║100│120║\n╚════╩═══╧═══╝\n\n\
+         ╔════╦══╗\n║Year║Q3║\n╟────╫──╢\n║2024║95║\n╚════╩══╝",
+        renderer.render_with_hints(&table, &[RenderHint::Bands(14)])
+    );
+
+    // a table that already fits is drawn in a single band
+    assert_eq!(
+        renderer.render(&table),
+        renderer.render_with_hints(&table, &[RenderHint::Bands(80)])
+    );
+}
+
+#[test]
+fn render_bands_with_captions() {
+    let table = Table::default()
+        .with_title(caption("Budget", HAlign::Left))
+        .with_footer(caption("Sum", HAlign::Left))
+        .with_cols(vec![
+            Col::new(Styles::default().with(Header(true))),
+            Col::default(),
+            Col::default(),
+            Col::default(),
+        ])
+        .with_row(Row::new(
+            Styles::default(),
+            vec![Cell::from("Year").with_footnote("a note"), "Q1".into(), "Q2".into(), "Q3".into()],
+        ))
+        .with_row(["2024", "100", "120", "95"]);
+    let renderer = Console(Decor::default().suppress_escape_codes());
+
+    // the title tops the first band and the footer closes the last, followed by each note once
+    assert_eq!(
+        "╔═ Budget ╤═══╗\n║Year¹║Q1 │Q2 ║\n╟─────╫───┼───╢\n║2024 ║100│120║\n╚═════╩═══╧═══╝\n\n\
+         ╔═════╦══╗\n║Year¹║Q3║\n╟─────╫──╢\n║2024 ║95║\n╚═ Sum ══╝\n¹ a note",
+        renderer.render_with_hints(&table, &[RenderHint::Bands(15)])
+    );
+}
+
+#[test]
+fn render_captions() {
+    #[derive(Debug)]
+    struct Case {
+        input: (&'static str, HAlign, bool),
+        expect: &'static str,
+    }
+
+    for case in [
+        Case {
+            input: ("Budget", HAlign::Left, true),
+            expect: "╔═ Budget ═╤══════╗\n║Department│Amount║\n╚═ Budget ═╧══════╝",
+        },
+        Case {
+            input: ("Budget", HAlign::Centred, true),
+            expect: "╔════ Budget ═════╗\n║Department│Amount║\n╚════ Budget ═════╝",
+        },
+        Case {
+            input: ("Budget", HAlign::Right, true),
+            expect: "╔════════ Budget ═╗\n║Department│Amount║\n╚════════ Budget ═╝",
+        },
+        Case {
+            input: ("Departmental budget", HAlign::Left, true),
+            expect: "╔═ Departmental… ═╗\n║Department│Amount║\n╚═ Departmental… ═╝",
+        },
+        Case {
+            input: ("Budget", HAlign::Right, false),
+            expect: "           Budget\nDepartment│Amount\n           Budget\n",
+        },
+    ] {
+        let table = Table::default()
+            .with_title(caption(case.input.0, case.input.1.clone()))
+            .with_footer(caption(case.input.0, case.input.1.clone()))
+            .with_row(["Department", "Amount"]);
+        let mut decor = Decor::default().suppress_escape_codes();
+        decor.draw_outer_border = case.input.2;
+        assert_eq!(case.expect, Console(decor).render(&table), "for {:?}", &case);
+    }
+}
+
+#[test]
+fn render_captions_over_short_borders() {
+    // the borders are too short to carry the captions, which are drawn on lines of their own
+    let table = Table::default()
+        .with_title(caption("Budget", HAlign::Left))
+        .with_footer(caption("Total", HAlign::Right))
+        .with_row(["a", "b"]);
+    assert_eq!(
+        "Budg…\n╔═╤═╗\n║a│b║\n╚═╧═╝\nTotal",
+        Console(Decor::default().suppress_escape_codes()).render(&table)
+    );
+}
+
+fn caption(text: &str, alignment: HAlign) -> Caption {
+    Caption::new(Styles::default().with(alignment), text)
+}
+
+#[test]
+fn render_footnotes() {
+    let table = Table::default()
+        .with_row(Row::new(
+            Styles::default(),
+            vec![Cell::from("Revenue").with_footnote("Excludes tax."), "9000".into()],
+        ))
+        .with_row(Row::new(
+            Styles::default(),
+            vec!["Costs".into(), Cell::from("4000").with_footnote("Estimated.\nSubject to audit.")],
+        ));
+    let mut decor = Decor::default().suppress_escape_codes();
+    assert_eq!(
+        "╔════════╤═════╗\n\
+         ║Revenue¹│9000 ║\n\
+         ╟────────┼─────╢\n\
+         ║Costs   │4000²║\n\
+         ╚════════╧═════╝\n\
+         ¹ Excludes tax.\n\
+         ² Estimated.\n  Subject to audit.",
+        Console(decor.clone()).render(&table)
+    );
+
+    decor.draw_outer_border = false;
+    assert_eq!(
+        "Revenue¹│9000 \n\
+         ────────┼─────\n\
+         Costs   │4000²\n\
+         ¹ Excludes tax.\n\
+         ² Estimated.\n  Subject to audit.\n",
+        Console(decor).render(&table)
+    );
+}
+
+#[test]
+fn render_merged_nested_borders() {
+    let table = Table::default()
+        .with_cols(vec![
+            Col::default(),
+            Col::new(Styles::default().with(MinWidth(14))),
+        ])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Plant".into(), "Readings".into()],
+        ))
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                "North\nwing\nonly".into(),
+                Table::default()
+                    .with_row(["Water", "19.3"])
+                    .with_row(["Oil", "65.1"])
+                    .into(),
+            ],
+        ))
+        .with_row(["South", "none"]);
+    let decor = Decor::default().suppress_escape_codes().merge_nested_borders();
+    assert_eq!(
+        "╔═════╤══════════════╗\n\
+         ║Plant│Readings      ║\n\
+         ╠═════╪═════╤════════╣\n\
+         ║North│Water│19.3    ║\n\
+         ║wing ├─────┼────────╢\n\
+         ║only │Oil  │65.1    ║\n\
+         ╟─────┼─────┴────────╢\n\
+         ║South│none          ║\n\
+         ╚═════╧══════════════╝",
+        Console(decor).render(&table)
+    );
+}
+
+#[test]
+fn render_merged_nested_edge_cases() {
+    struct Case {
+        input: (Table, Decor),
+        expect: &'static str,
+    }
+
+    let decor = Decor::default().suppress_escape_codes().merge_nested_borders();
+    for case in [
+        // without inner horizontal borders, neither table draws any
+        Case {
+            input: (
+                Table::default()
+                    .with_row(Row::new(Styles::default(), vec!["North".into(), readings().into()]))
+                    .with_row(["South", "none"]),
+                decor.clone().suppress_inner_horizontal_border(),
+            ),
+            expect: "╔═════╤═════╤════╗\n\
+                     ║North│Water│19.3║\n\
+                     ║     │Oil  │65.1║\n\
+                     ║South│none      ║\n\
+                     ╚═════╧══════════╝",
+        },
+        // the nested table fills a cell spanning several rows
+        Case {
+            input: (
+                Table::default()
+                    .with_row(Row::new(
+                        Styles::default(),
+                        vec![
+                            "North".into(),
+                            Cell::new(Styles::default().with(RowSpan(2)), readings().into()),
+                        ],
+                    ))
+                    .with_row(["South"])
+                    .with_row(["East", "none"]),
+                decor.clone(),
+            ),
+            expect: "╔═════╤═════╤════╗\n\
+                     ║North│Water│19.3║\n\
+                     ╟─────┼─────┼────╢\n\
+                     ║South│Oil  │65.1║\n\
+                     ╟─────┼─────┴────╢\n\
+                     ║East │none      ║\n\
+                     ╚═════╧══════════╝",
+        },
+        // a nested table within a nested table
+        Case {
+            input: (
+                Table::default()
+                    .with_row(Row::new(
+                        Styles::default(),
+                        vec![
+                            "North".into(),
+                            Table::default()
+                                .with_row(Row::new(
+                                    Styles::default(),
+                                    vec!["Water".into(), readings().into()],
+                                ))
+                                .with_row(["Oil", "none"])
+                                .into(),
+                        ],
+                    ))
+                    .with_row(["South", "none"]),
+                decor.clone(),
+            ),
+            expect: "╔═════╤═════╤═════╤════╗\n\
+                     ║North│Water│Water│19.3║\n\
+                     ║     │     ├─────┼────╢\n\
+                     ║     │     │Oil  │65.1║\n\
+                     ║     ├─────┼─────┴────╢\n\
+                     ║     │Oil  │none      ║\n\
+                     ╟─────┼─────┴──────────╢\n\
+                     ║South│none            ║\n\
+                     ╚═════╧════════════════╝",
+        },
+        // a nested table that doesn't fit within its cell is drawn as text
+        Case {
+            input: (
+                Table::default()
+                    .with_cols(vec![Col::default(), Col::new(Styles::default().with(MaxWidth(3)))])
+                    .with_row(Row::new(
+                        Styles::default(),
+                        vec!["North".into(), Table::default().with_row(["ab", "cd"]).into()],
+                    ))
+                    .with_row(["South", "none"]),
+                decor.clone(),
+            ),
+            expect: "╔═════╤═══╗\n\
+                     ║North│ab│║\n\
+                     ║     │cd ║\n\
+                     ╟─────┼───╢\n\
+                     ║South│non║\n\
+                     ║     │e  ║\n\
+                     ╚═════╧═══╝",
+        },
+    ] {
+        let (table, decor) = case.input;
+        assert_eq!(case.expect, Console(decor).render(&table));
+    }
+}
+
+#[test]
+fn render_merged_nested_colours() {
+    let table = Table::with_styles(Styles::default().with(BorderFg(Palette16::Blue.into()))).with_row(
+        Row::new(
+            Styles::default(),
+            vec![Cell::new(Styles::default().with(TextFg(Palette16::Red.into())), readings().into())],
+        ),
+    );
+    let decor = Decor::default().merge_nested_borders().with_colour_depth(ColourDepth::Palette16);
+    let rendered = Console(decor).render(&table);
+
+    // the nested table's border glyphs take on the border colour, and its text the cell's
+    assert!(rendered.contains("\x1b[34m│\x1b[0m"), "in {:?}", rendered);
+    assert!(rendered.contains("\x1b[34m┼\x1b[0m"), "in {:?}", rendered);
+    assert!(rendered.contains("\x1b[31mWater\x1b[0m"), "in {:?}", rendered);
+    assert!(!rendered.contains("\x1b[31m│"), "in {:?}", rendered);
+}
+
+fn readings() -> Table {
+    Table::default().with_row(["Water", "19.3"]).with_row(["Oil", "65.1"])
+}
+
+#[test]
+fn from_strokes() {
+    assert_eq!(
+        Decor::double_outline(),
+        Decor::from_strokes(Stroke::Double, Stroke::Single, Stroke::Double)
+    );
+
+    // glyphs joining strokes of different weights
+    let decor = Decor::from_strokes(Stroke::Heavy, Stroke::Single, Stroke::Heavy);
+    assert_eq!('┠', decor.up_bold_right_thin_down_bold);
+    assert_eq!('┿', decor.up_thin_right_bold_down_thin_left_bold);
+    let decor = Decor::from_strokes(Stroke::Double, Stroke::Heavy, Stroke::Double);
+    assert_eq!('╠', decor.up_bold_right_thin_down_bold);
+    assert_eq!('╹', decor.up_thin);
+    let decor = Decor::from_strokes(Stroke::Single, Stroke::Double, Stroke::Single);
+    assert_eq!('║', decor.up_thin);
+
+    // any ASCII stroke makes the others ASCII
+    let decor = Decor::from_strokes(Stroke::Heavy, Stroke::Ascii, Stroke::Double);
+    assert_eq!(Decor::ascii(), decor);
+}
+
+#[test]
+fn render_header_strokes() {
+    #[derive(Debug)]
+    struct Case {
+        input: (Stroke, Stroke, Stroke),
+        expect: &'static str,
+    }
+
+    for case in [
+        // the header stroke differs from the others
+        Case {
+            input: (Stroke::Double, Stroke::Single, Stroke::Heavy),
+            expect: "╔═╤═╗\n║h│h║\n╠━┿━╣\n║a│b║\n╟─┼─╢\n║c│d║\n╚═╧═╝",
+        },
+        // the header stroke matches the inner lines
+        Case {
+            input: (Stroke::Heavy, Stroke::Single, Stroke::Single),
+            expect: "┏━┯━┓\n┃h│h┃\n┠─┼─┨\n┃a│b┃\n┠─┼─┨\n┃c│d┃\n┗━┷━┛",
+        },
+        // a single header stroke between heavier lines
+        Case {
+            input: (Stroke::Heavy, Stroke::Dashed, Stroke::Single),
+            expect: "┏━┯━┓\n┃h┆h┃\n┠─┼─┨\n┃a┆b┃\n┠┄┼┄┨\n┃c┆d┃\n┗━┷━┛",
+        },
+    ] {
+        let table = Table::default()
+            .with_row(Row::new(
+                Styles::default().with(Header(true)),
+                vec!["h".into(), "h".into()],
+            ))
+            .with_row(["a", "b"])
+            .with_row(["c", "d"]);
+        let (outer, inner, header) = case.input;
+        let decor = Decor::from_strokes(outer, inner, header).suppress_escape_codes();
+        assert_eq!(case.expect, Console(decor).render(&table), "for {:?}", &case);
+    }
+}
+
+#[test]
+fn render_presets() {
+    #[derive(Debug)]
+    struct Case {
+        input: Decor,
+        expect: &'static str,
+    }
+
+    for case in [
+        Case {
+            input: Decor::single_line(),
+            expect: "┌─────┬───┐\n│Name │Qty│\n├─────┼───┤\n│Pears│12 │\n└─────┴───┘",
+        },
+        Case {
+            input: Decor::rounded(),
+            expect: "╭─────┬───╮\n│Name │Qty│\n├─────┼───┤\n│Pears│12 │\n╰─────┴───╯",
+        },
+        Case {
+            input: Decor::heavy_outline(),
+            expect: "┏━━━━━┯━━━┓\n┃Name │Qty┃\n┣━━━━━┿━━━┫\n┃Pears│12 ┃\n┗━━━━━┷━━━┛",
+        },
+        Case {
+            input: Decor::ascii(),
+            expect: "+-----+---+\n|Name |Qty|\n+-----+---+\n|Pears|12 |\n+-----+---+",
+        },
+        Case {
+            input: Decor::compact(),
+            expect: "Name |Qty\n-----+---\nPears|12 \n",
+        },
+        Case {
+            input: Decor::markdown_like(),
+            expect: "Name |Qty\n-----|---\nPears|12 \n",
+        },
+        Case {
+            input: Decor::borderless(),
+            expect: "Name  Qty\nPears 12 \n",
+        },
+    ] {
+        let table = Table::default()
+            .with_row(Row::new(
+                Styles::default().with(Header(true)),
+                vec!["Name".into(), "Qty".into()],
+            ))
+            .with_row(["Pears", "12"]);
+        let renderer = Console(case.input.clone().suppress_escape_codes());
+        assert_eq!(case.expect, renderer.render(&table), "for {:?}", &case);
+    }
+}
+
+#[test]
+fn render_border_styles() {
+    let table = Table::default()
+        .with_row(Row::new(
+            Styles::default().with(BorderBottom(Line::None)),
+            vec!["Apples".into(), "3".into()],
+        ))
+        .with_row(Row::new(
+            Styles::default().with(BorderBottom(Line::Dashed)),
+            vec!["Pears".into(), "2".into()],
+        ))
+        .with_row(Row::new(
+            Styles::default().with(BorderTop(Line::Heavy)),
+            vec!["Total".into(), "5".into()],
+        ));
+    assert_eq!(
+        "┌──────┬─┐\n\
+         │Apples│3│\n\
+         │Pears │2│\n\
+         ┝━━━━━━┿━┥\n\
+         │Total │5│\n\
+         └──────┴─┘",
+        Console(Decor::single_line().suppress_escape_codes()).render(&table)
+    );
+    assert_eq!(
+        "+------+-+\n\
+         |Apples|3|\n\
+         |Pears |2|\n\
+         +------+-+\n\
+         |Total |5|\n\
+         +------+-+",
+        Console(Decor::ascii().suppress_escape_codes()).render(&table)
+    );
+}
+
+#[test]
+fn render_banding() {
+    let table = Table::with_styles(
+        Styles::default().with(Banding::new(Styles::default().with(Bold(true)))),
+    )
+    .with_row(Row::new(Styles::default().with(Header(true)), vec!["h".into()]))
+    .with_rows(["a", "b", "c", "d"].map(|text| Row::from([text])));
+
+    let renderer = Console(Decor::default().with_colour_depth(ColourDepth::Monochrome));
+    let rendered = renderer.render(&table);
+    for (text, shaded) in [("h", false), ("a", false), ("b", true), ("c", false), ("d", true)] {
+        let line = rendered.lines().find(|line| line.contains(text)).unwrap();
+        assert_eq!(shaded, line.contains("\x1b[1m"), "for {text} in {rendered:?}");
+    }
+}
@@ -0,0 +1,140 @@
+use crate::renderer::{RenderHint, Renderer};
+use crate::style::{Header, Separator, Style};
+use crate::table::Table;
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+/// Renders a [`Table`] as delimiter-separated values, quoting fields per RFC 4180.
+///
+/// Only the data is rendered; separator rows and columns are skipped and styles are ignored.
+/// Cells hidden by another cell's span are emitted as empty fields. The title and footer of the
+/// table are omitted, as they have no equivalent in delimiter-separated values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Csv {
+    pub delimiter: char,
+    pub quote: char,
+    pub line_terminator: &'static str,
+    pub header_once: bool,
+}
+
+impl Default for Csv {
+    fn default() -> Self {
+        Self::comma_separated()
+    }
+}
+
+impl Csv {
+    /// Comma-separated values, terminated by CRLF.
+    pub fn comma_separated() -> Self {
+        Self {
+            delimiter: ',',
+            quote: '"',
+            line_terminator: "\r\n",
+            header_once: false,
+        }
+    }
+
+    /// Tab-separated values, terminated by LF.
+    pub fn tab_separated() -> Self {
+        Self {
+            delimiter: '\t',
+            quote: '"',
+            line_terminator: "\n",
+            header_once: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    #[must_use]
+    pub fn with_quote(mut self, quote: char) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    #[must_use]
+    pub fn with_line_terminator(mut self, line_terminator: &'static str) -> Self {
+        self.line_terminator = line_terminator;
+        self
+    }
+
+    /// Emits only the first [`Header`] row, skipping any header rows that follow.
+    #[must_use]
+    pub fn header_once(mut self) -> Self {
+        self.header_once = true;
+        self
+    }
+
+    /// Quotes the given field if it contains the delimiter, the quote character or a line break,
+    /// doubling any embedded quote characters.
+    pub fn quote_field<'a>(&self, field: &'a str) -> Cow<'a, str> {
+        let needs_quoting = field
+            .chars()
+            .any(|ch| ch == self.delimiter || ch == self.quote || ch == '\r' || ch == '\n');
+        if needs_quoting {
+            let mut buf = String::with_capacity(field.len() + 2);
+            buf.push(self.quote);
+            for ch in field.chars() {
+                if ch == self.quote {
+                    buf.push(self.quote);
+                }
+                buf.push(ch);
+            }
+            buf.push(self.quote);
+            Cow::Owned(buf)
+        } else {
+            Cow::Borrowed(field)
+        }
+    }
+}
+
+impl Renderer for Csv {
+    type Output = String;
+
+    fn render_with_hints(&self, table: &Table, _: &[RenderHint]) -> Self::Output {
+        let mut buf = String::new();
+        let spans = table.spans();
+        let mut header_printed = false;
+        for row in 0..table.num_rows() {
+            let row_styles = table.row(row).blended_styles();
+            if Separator::resolve_or_default(&row_styles).0 {
+                continue;
+            }
+            if Header::resolve_or_default(&row_styles).0 {
+                if self.header_once && header_printed {
+                    continue;
+                }
+                header_printed = true;
+            }
+
+            let mut first = true;
+            for col in 0..table.num_cols() {
+                if Separator::resolve_or_default(&table.col(col).blended_styles()).0 {
+                    continue;
+                }
+                if !first {
+                    buf.push(self.delimiter);
+                }
+                first = false;
+
+                if !spans.is_origin(col, row) {
+                    // cells hidden by another cell's span are left blank
+                    continue;
+                }
+                if let Some(cell) = *table.cell(col, row) {
+                    let data = cell.data().render(self);
+                    buf.push_str(&self.quote_field(&data));
+                }
+            }
+            buf.push_str(self.line_terminator);
+        }
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,56 @@
+use crate::renderer::csv::Csv;
+use crate::renderer::Renderer;
+use crate::style::{Header, Styles};
+use crate::table::{Col, Content, Row, Table};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+
+#[test]
+fn quote_field() {
+    let csv = Csv::default();
+    assert_eq!("plain", csv.quote_field("plain"));
+    assert_eq!("\"a,b\"", csv.quote_field("a,b"));
+    assert_eq!("\"say \"\"hi\"\"\"", csv.quote_field("say \"hi\""));
+    assert_eq!("\"two\nlines\"", csv.quote_field("two\nlines"));
+
+    let tsv = Csv::tab_separated();
+    assert_eq!("a,b", tsv.quote_field("a,b"));
+    assert_eq!("\"a\tb\"", tsv.quote_field("a\tb"));
+}
+
+#[test]
+fn render_skips_separators() {
+    let table = Table::default()
+        .with_cols(vec![Col::default(), Col::separator(), Col::default()])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Department".into(), "".into(), "Budget".into()],
+        ))
+        .with_row(Row::separator())
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                "Sales, EMEA".into(),
+                "".into(),
+                Content::Computed(Box::new(|| String::from("90000"))).into(),
+            ],
+        ))
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Department".into(), "".into(), "Budget".into()],
+        ));
+
+    assert_eq!(
+        "Department,Budget\r\n\"Sales, EMEA\",90000\r\nDepartment,Budget\r\n",
+        Csv::default().render(&table)
+    );
+    assert_eq!(
+        "Department;Budget\nSales, EMEA;90000\n",
+        Csv::default()
+            .with_delimiter(';')
+            .with_line_terminator("\n")
+            .header_once()
+            .render(&table)
+    );
+}
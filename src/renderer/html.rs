@@ -0,0 +1,392 @@
+use crate::renderer::{RenderHint, Renderer, NEWLINE};
+use crate::style::{
+    Blink, Bold, BorderBg, BorderFg, Colour, FillBg, HAlign, Header, Italic, Palette16,
+    Separator, Strikethrough, Style, Styled, Styles, TextBg, TextFg, Underline, VAlign,
+};
+use crate::table::{Caption, Content, Element, Footnote, Spans, Table};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// Renders a [`Table`] as an HTML `<table>` element, mapping styles to inline CSS.
+///
+/// Leading rows styled with [`Header`] are placed in a `<thead>` section; the remaining rows
+/// are placed in the `<tbody>`. Cells in header rows and header columns are emitted as `<th>`.
+/// Separator rows and columns carry no data and are omitted from the output. Cells spanning
+/// multiple columns or rows are emitted with the `colspan` and `rowspan` attributes. The title of
+/// the table is emitted as its `<caption>`, and the footer as a single cell in the `<tfoot>`.
+/// Footnotes are referenced by numbered `<sup>` markers, and listed in the `<tfoot>`, below the
+/// footer, one cell each.
+#[derive(Default)]
+pub struct Html();
+
+impl Renderer for Html {
+    type Output = String;
+
+    fn render_with_hints(&self, table: &Table, _: &[RenderHint]) -> Self::Output {
+        assert!(!table.is_empty(), "table cannot be empty");
+        let mut buf = String::new();
+
+        let table_css = table_css(table.styles());
+        if table_css.is_empty() {
+            buf.push_str("<table>");
+        } else {
+            buf.push_str("<table style=\"");
+            buf.push_str(&table_css);
+            buf.push_str("\">");
+        }
+        buf.push_str(NEWLINE);
+
+        if table.title().is_some() {
+            buf.push_str("<caption");
+            append_caption(&table.title(), &mut buf);
+            buf.push_str("</caption>");
+            buf.push_str(NEWLINE);
+        }
+
+        let grid = Grid::new(table);
+
+        // leading header rows comprise the head section; the rest go into the body
+        let head_rows = grid
+            .rows
+            .iter()
+            .take_while(|&&row| is_header_row(table, row))
+            .count();
+
+        if head_rows > 0 {
+            buf.push_str("<thead>");
+            buf.push_str(NEWLINE);
+            for &row in &grid.rows[..head_rows] {
+                print_row(self, table, &grid, row, &mut buf);
+            }
+            buf.push_str("</thead>");
+            buf.push_str(NEWLINE);
+        }
+
+        if head_rows < grid.rows.len() {
+            buf.push_str("<tbody>");
+            buf.push_str(NEWLINE);
+            for &row in &grid.rows[head_rows..] {
+                print_row(self, table, &grid, row, &mut buf);
+            }
+            buf.push_str("</tbody>");
+            buf.push_str(NEWLINE);
+        }
+
+        if table.footer().is_some() || !grid.footnotes.is_empty() {
+            // the footer and each of the notes occupy a single cell, spanning all columns
+            buf.push_str("<tfoot>");
+            buf.push_str(NEWLINE);
+            if table.footer().is_some() {
+                open_footer_cell(&grid, &mut buf);
+                append_caption(&table.footer(), &mut buf);
+                buf.push_str("</td></tr>");
+                buf.push_str(NEWLINE);
+            }
+            for footnote in &grid.footnotes {
+                open_footer_cell(&grid, &mut buf);
+                buf.push('>');
+                append_marker(footnote.number, &mut buf);
+                buf.push(' ');
+                append_escaped(&footnote.text, &mut buf);
+                buf.push_str("</td></tr>");
+                buf.push_str(NEWLINE);
+            }
+            buf.push_str("</tfoot>");
+            buf.push_str(NEWLINE);
+        }
+
+        buf.push_str("</table>");
+        buf
+    }
+}
+
+/// The rows and columns emitted, which exclude separators, alongside the spans of the cells and
+/// the footnotes referenced by the emitted cells.
+struct Grid {
+    cols: Vec<usize>,
+    rows: Vec<usize>,
+    spans: Spans,
+    footnotes: Vec<Footnote>,
+}
+
+impl Grid {
+    fn new(table: &Table) -> Self {
+        let cols = (0..table.num_cols())
+            .filter(|&col| !Separator::resolve_or_default(&table.col(col).blended_styles()).0)
+            .collect::<Vec<_>>();
+        let rows = (0..table.num_rows())
+            .filter(|&row| !Separator::resolve_or_default(&table.row(row).blended_styles()).0)
+            .collect::<Vec<_>>();
+        let footnotes = table
+            .footnotes()
+            .into_iter()
+            .filter(|footnote| cols.contains(&footnote.col) && rows.contains(&footnote.row))
+            .collect();
+        Self {
+            cols,
+            rows,
+            spans: table.spans(),
+            footnotes,
+        }
+    }
+
+    /// The number of the footnote referenced by the cell at the given position, if any.
+    fn footnote_number(&self, col: usize, row: usize) -> Option<usize> {
+        self.footnotes
+            .iter()
+            .find(|footnote| footnote.col == col && footnote.row == row)
+            .map(|footnote| footnote.number)
+    }
+}
+
+/// Opens a row of the `<tfoot>` and a cell spanning all columns, leaving the opening tag of the
+/// cell incomplete.
+fn open_footer_cell(grid: &Grid, buf: &mut String) {
+    buf.push_str("<tr><td");
+    if grid.cols.len() > 1 {
+        write!(buf, " colspan=\"{}\"", grid.cols.len()).unwrap();
+    }
+}
+
+/// Appends the marker referencing the footnote of the given number.
+fn append_marker(number: usize, buf: &mut String) {
+    write!(buf, "<sup>{number}</sup>").unwrap();
+}
+
+fn is_header_row(table: &Table, row: usize) -> bool {
+    Header::resolve_or_default(&table.row(row).blended_styles()).0
+}
+
+/// Completes the opening tag of the element holding the caption with its inline CSS, and appends
+/// the caption text.
+fn append_caption(caption: &Element<'_, Caption>, buf: &mut String) {
+    let styles = caption.blended_styles();
+    let cell_css = cell_css(&styles);
+    if !cell_css.is_empty() {
+        buf.push_str(" style=\"");
+        buf.push_str(&cell_css);
+        buf.push('"');
+    }
+    buf.push('>');
+    if let Some(caption) = **caption {
+        append_text(caption.text(), &text_css(&styles), buf);
+    }
+}
+
+fn is_header_col(table: &Table, col: usize) -> bool {
+    Header::resolve_or_default(&table.col(col).blended_styles()).0
+}
+
+fn print_row(renderer: &Html, table: &Table, grid: &Grid, row: usize, buf: &mut String) {
+    let header_row = is_header_row(table, row);
+    buf.push_str("<tr>");
+    for &col in &grid.cols {
+        let (span_cols, span_rows) = grid.spans.extent(col, row);
+        if span_cols == 0 {
+            // hidden by another cell's span
+            continue;
+        }
+        // the content is rendered before the styles are blended, so that conditions are evaluated
+        // against the rendered text
+        let cell = table.cell(col, row);
+        let mut fragments = Vec::new();
+        if let Some(cell) = *cell {
+            append_fragments(renderer, cell.data(), &mut fragments);
+        }
+        let text = fragments
+            .iter()
+            .filter_map(|fragment| match fragment {
+                Fragment::Text(text) => Some(&**text),
+                Fragment::Markup(_) => None,
+            })
+            .collect::<String>();
+        let styles = cell.with_text(&text).blended_styles();
+        let tag = if header_row || is_header_col(table, col) {
+            "th"
+        } else {
+            "td"
+        };
+
+        buf.push('<');
+        buf.push_str(tag);
+        // separator rows and columns are omitted, and therefore don't count towards the span
+        let span_cols = grid.cols.iter().filter(|&&c| c >= col && c < col + span_cols).count();
+        if span_cols > 1 {
+            write!(buf, " colspan=\"{span_cols}\"").unwrap();
+        }
+        let span_rows = grid.rows.iter().filter(|&&r| r >= row && r < row + span_rows).count();
+        if span_rows > 1 {
+            write!(buf, " rowspan=\"{span_rows}\"").unwrap();
+        }
+        let cell_css = cell_css(&styles);
+        if !cell_css.is_empty() {
+            buf.push_str(" style=\"");
+            buf.push_str(&cell_css);
+            buf.push('"');
+        }
+        buf.push('>');
+
+        let text_css = text_css(&styles);
+        for fragment in &fragments {
+            match fragment {
+                Fragment::Text(text) => append_text(text, &text_css, buf),
+                Fragment::Markup(markup) => buf.push_str(markup),
+            }
+        }
+        if let Some(number) = grid.footnote_number(col, row) {
+            append_marker(number, buf);
+        }
+
+        buf.push_str("</");
+        buf.push_str(tag);
+        buf.push('>');
+    }
+    buf.push_str("</tr>");
+    buf.push_str(NEWLINE);
+}
+
+/// A piece of the content of a cell: either text, which is yet to be escaped, or the markup of a
+/// nested table.
+enum Fragment<'a> {
+    Text(Cow<'a, str>),
+    Markup(String),
+}
+
+/// Appends the fragments of the content, rendering nested tables as child `<table>` elements.
+fn append_fragments<'a>(renderer: &Html, content: &'a Content, fragments: &mut Vec<Fragment<'a>>) {
+    match content {
+        Content::Label(s) => fragments.push(Fragment::Text(Cow::Borrowed(s))),
+        Content::Computed(f) => fragments.push(Fragment::Text(Cow::Owned(f()))),
+        Content::Nested(table) => fragments.push(Fragment::Markup(
+            renderer.render_with_hints(table, &[RenderHint::Nested]),
+        )),
+        Content::Composite(contents) => {
+            for content in contents {
+                append_fragments(renderer, content, fragments);
+            }
+        }
+    }
+}
+
+fn append_text(s: &str, text_css: &str, buf: &mut String) {
+    if text_css.is_empty() {
+        append_escaped(s, buf);
+    } else {
+        buf.push_str("<span style=\"");
+        buf.push_str(text_css);
+        buf.push_str("\">");
+        append_escaped(s, buf);
+        buf.push_str("</span>");
+    }
+}
+
+/// Appends the given string to the buffer, escaping HTML special characters. Line breaks
+/// are converted to `<br>` elements, including trailing ones.
+fn append_escaped(s: &str, buf: &mut String) {
+    for (i, line) in s.split('\n').enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if i > 0 {
+            buf.push_str("<br>");
+        }
+        for ch in line.chars() {
+            match ch {
+                '&' => buf.push_str("&amp;"),
+                '<' => buf.push_str("&lt;"),
+                '>' => buf.push_str("&gt;"),
+                '"' => buf.push_str("&quot;"),
+                '\'' => buf.push_str("&#39;"),
+                _ => buf.push(ch),
+            }
+        }
+    }
+}
+
+fn table_css(styles: &Styles) -> String {
+    let mut css = String::new();
+    if let Some(color) = BorderFg::resolve(styles).and_then(|fg| css_color(&fg.0)) {
+        push_property(&mut css, "border-collapse", "collapse");
+        push_property(&mut css, "border", &format!("1px solid {color}"));
+    }
+    if let Some(color) = BorderBg::resolve(styles).and_then(|bg| css_color(&bg.0)) {
+        push_property(&mut css, "background-color", &color);
+    }
+    css
+}
+
+fn cell_css(styles: &Styles) -> String {
+    let mut css = String::new();
+    match HAlign::resolve_or_default(styles).as_ref() {
+        HAlign::Left => {}
+        HAlign::Centred => push_property(&mut css, "text-align", "center"),
+        HAlign::Right => push_property(&mut css, "text-align", "right"),
+    }
+    if let Some(alignment) = VAlign::resolve(styles) {
+        let alignment = match alignment {
+            VAlign::Top => "top",
+            VAlign::Middle => "middle",
+            VAlign::Bottom => "bottom",
+        };
+        push_property(&mut css, "vertical-align", alignment);
+    }
+    if let Some(color) = BorderFg::resolve(styles).and_then(|fg| css_color(&fg.0)) {
+        push_property(&mut css, "border", &format!("1px solid {color}"));
+    }
+    if let Some(color) = FillBg::resolve(styles).and_then(|bg| css_color(&bg.0)) {
+        push_property(&mut css, "background-color", &color);
+    }
+    css
+}
+
+fn text_css(styles: &Styles) -> String {
+    let mut css = String::new();
+    if Bold::resolve_or_default(styles).0 {
+        push_property(&mut css, "font-weight", "bold");
+    }
+    if Italic::resolve_or_default(styles).0 {
+        push_property(&mut css, "font-style", "italic");
+    }
+
+    let mut decorations = Vec::new();
+    if Underline::resolve_or_default(styles).0 {
+        decorations.push("underline");
+    }
+    if Strikethrough::resolve_or_default(styles).0 {
+        decorations.push("line-through");
+    }
+    if Blink::resolve_or_default(styles).0 {
+        decorations.push("blink");
+    }
+    if !decorations.is_empty() {
+        push_property(&mut css, "text-decoration", &decorations.join(" "));
+    }
+
+    if let Some(color) = TextFg::resolve(styles).and_then(|fg| css_color(&fg.0)) {
+        push_property(&mut css, "color", &color);
+    }
+    if let Some(color) = TextBg::resolve(styles).and_then(|bg| css_color(&bg.0)) {
+        push_property(&mut css, "background-color", &color);
+    }
+    css
+}
+
+fn push_property(css: &mut String, property: &str, value: &str) {
+    css.push_str(property);
+    css.push(':');
+    css.push_str(value);
+    css.push(';');
+}
+
+/// Obtains the CSS equivalent of the given colour, or `None` if the colour should be inherited.
+fn css_color(colour: &Colour) -> Option<Cow<'static, str>> {
+    match (colour, colour.rgb()) {
+        (Colour::Palette16(Palette16::Hidden), _) => Some(Cow::Borrowed("transparent")),
+        (_, Some((r, g, b))) => Some(Cow::Owned(format!("#{r:02x}{g:02x}{b:02x}"))),
+        (_, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests;
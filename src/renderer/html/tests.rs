@@ -0,0 +1,185 @@
+use crate::renderer::html::Html;
+use crate::renderer::Renderer;
+use crate::style::{
+    Banding, Bold, ColSpan, Colour, FillBg, HAlign, Header, Palette16, RowSpan, Styles, TextFg,
+};
+use crate::table::{Caption, Cell, Col, Row, Table};
+use alloc::string::String;
+use alloc::vec;
+
+#[test]
+fn append_escaped() {
+    let mut buf = String::new();
+    super::append_escaped("<a href=\"x\">Tom & Jerry's</a>\nnext", &mut buf);
+    assert_eq!(
+        "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;<br>next",
+        buf
+    );
+
+    // trailing line breaks are retained
+    let mut buf = String::new();
+    super::append_escaped("a\r\nb\n\n", &mut buf);
+    assert_eq!("a<br>b<br><br>", buf);
+}
+
+#[test]
+fn render_header_and_body() {
+    let table = Table::default()
+        .with_cols(vec![
+            Col::default(),
+            Col::separator(),
+            Col::new(Styles::default().with(HAlign::Right)),
+        ])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Department".into(), "".into(), "Budget".into()],
+        ))
+        .with_row(Row::separator())
+        .with_row(Row::new(
+            Styles::default().with(Bold(true)),
+            vec!["Sales".into(), "".into(), "90000".into()],
+        ));
+
+    let expected = "<table>\n\
+                    <thead>\n\
+                    <tr><th>Department</th><th style=\"text-align:right;\">Budget</th></tr>\n\
+                    </thead>\n\
+                    <tbody>\n\
+                    <tr><td><span style=\"font-weight:bold;\">Sales</span></td>\
+                    <td style=\"text-align:right;\"><span style=\"font-weight:bold;\">90000</span></td></tr>\n\
+                    </tbody>\n\
+                    </table>";
+    assert_eq!(expected, Html::default().render(&table));
+}
+
+#[test]
+fn render_nested() {
+    let inner = Table::default().with_row(["a<b"]);
+    let table = Table::default().with_row(Row::new(Styles::default(), vec![inner.into()]));
+
+    let expected = "<table>\n\
+                    <tbody>\n\
+                    <tr><td><table>\n\
+                    <tbody>\n\
+                    <tr><td>a&lt;b</td></tr>\n\
+                    </tbody>\n\
+                    </table></td></tr>\n\
+                    </tbody>\n\
+                    </table>";
+    assert_eq!(expected, Html::default().render(&table));
+}
+
+#[test]
+fn render_spans() {
+    let table = Table::default()
+        .with_cols(vec![Col::default(), Col::separator(), Col::default()])
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                Cell::new(Styles::default().with(ColSpan(3)).with(RowSpan(2)), "a".into()),
+                "".into(),
+                "".into(),
+            ],
+        ))
+        .with_row(["", "", ""]);
+
+    let expected = "<table>\n\
+                    <tbody>\n\
+                    <tr><td colspan=\"2\" rowspan=\"2\">a</td></tr>\n\
+                    <tr></tr>\n\
+                    </tbody>\n\
+                    </table>";
+    assert_eq!(expected, Html::default().render(&table));
+}
+
+#[test]
+fn render_colours() {
+    let table = Table::default().with_row(Row::new(
+        Styles::default(),
+        vec![
+            Cell::new(Styles::default().with(TextFg(Colour::Rgb(18, 52, 86))), "a".into()),
+            Cell::new(Styles::default().with(FillBg(Colour::Palette256(196))), "b".into()),
+            Cell::new(Styles::default().with(TextFg(Palette16::Default.into())), "c".into()),
+        ],
+    ));
+
+    let expected = "<table>\n\
+                    <tbody>\n\
+                    <tr><td><span style=\"color:#123456;\">a</span></td>\
+                    <td style=\"background-color:#ff0000;\">b</td>\
+                    <td>c</td></tr>\n\
+                    </tbody>\n\
+                    </table>";
+    assert_eq!(expected, Html::default().render(&table));
+}
+
+#[test]
+fn render_banding() {
+    let table = Table::with_styles(Styles::default().with(
+        Banding::new(Styles::default().with(FillBg(Colour::Rgb(238, 238, 238)))).with_rows(2),
+    ))
+    .with_row(Row::new(Styles::default().with(Header(true)), vec!["h".into()]))
+    .with_rows(["a", "b", "c", "d", "e"].map(|text| Row::from([text])));
+
+    let expected = "<table>\n\
+                    <thead>\n\
+                    <tr><th>h</th></tr>\n\
+                    </thead>\n\
+                    <tbody>\n\
+                    <tr><td>a</td></tr>\n\
+                    <tr><td>b</td></tr>\n\
+                    <tr><td style=\"background-color:#eeeeee;\">c</td></tr>\n\
+                    <tr><td style=\"background-color:#eeeeee;\">d</td></tr>\n\
+                    <tr><td>e</td></tr>\n\
+                    </tbody>\n\
+                    </table>";
+    assert_eq!(expected, Html::default().render(&table));
+}
+
+#[test]
+fn render_captions() {
+    let table = Table::default()
+        .with_title(Caption::new(Styles::default().with(Bold(true)), "Budget"))
+        .with_footer(Caption::new(Styles::default().with(HAlign::Right), "FY <2024>"))
+        .with_row(["Sales", "9000"]);
+    assert_eq!(
+        "<table>\n\
+         <caption><span style=\"font-weight:bold;\">Budget</span></caption>\n\
+         <tbody>\n\
+         <tr><td>Sales</td><td>9000</td></tr>\n\
+         </tbody>\n\
+         <tfoot>\n\
+         <tr><td colspan=\"2\" style=\"text-align:right;\">FY &lt;2024&gt;</td></tr>\n\
+         </tfoot>\n\
+         </table>",
+        Html::default().render(&table)
+    );
+}
+
+#[test]
+fn render_footnotes() {
+    let table = Table::default()
+        .with_cols(vec![Col::default(), Col::separator(), Col::default()])
+        .with_footer(Caption::new(Styles::default(), "FY 2024"))
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                Cell::from("Sales").with_footnote("excl. <tax>"),
+                Cell::from("").with_footnote("omitted"),
+                Cell::from("9000").with_footnote("estimate"),
+            ],
+        ));
+    assert_eq!(
+        "<table>\n\
+         <tbody>\n\
+         <tr><td>Sales<sup>1</sup></td><td>9000<sup>3</sup></td></tr>\n\
+         </tbody>\n\
+         <tfoot>\n\
+         <tr><td colspan=\"2\">FY 2024</td></tr>\n\
+         <tr><td colspan=\"2\"><sup>1</sup> excl. &lt;tax&gt;</td></tr>\n\
+         <tr><td colspan=\"2\"><sup>3</sup> estimate</td></tr>\n\
+         </tfoot>\n\
+         </table>",
+        Html::default().render(&table)
+    );
+}
@@ -0,0 +1,40 @@
+//! Bridging of [`core::fmt::Write`] to [`std::io::Write`], for streaming rendered output to a byte
+//! sink.
+
+use core::fmt;
+use std::io;
+
+/// Adapts a [`std::io::Write`] to a [`core::fmt::Write`], retaining the first I/O error that is
+/// encountered, as [`fmt::Error`] carries no detail of its own.
+pub struct IoAdapter<W> {
+    inner: W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> IoAdapter<W> {
+    pub fn new(inner: W) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Converts the outcome of a formatting operation into an I/O result, recovering the
+    /// underlying I/O error if one was encountered.
+    pub fn into_result(self, result: fmt::Result) -> io::Result<()> {
+        match (result, self.error) {
+            (Ok(()), _) => Ok(()),
+            (Err(_), Some(err)) => Err(err),
+            (Err(_), None) => Err(io::Error::new(io::ErrorKind::Other, "formatter error")),
+        }
+    }
+}
+
+impl<W: io::Write> fmt::Write for IoAdapter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Some(err);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,43 @@
+use crate::renderer::console::{Console, Decor};
+use crate::renderer::markdown::Markdown;
+use crate::renderer::{Renderer, StreamRenderer};
+use crate::table::Table;
+use alloc::vec::Vec;
+use std::io;
+
+fn sample_table() -> Table {
+    Table::default()
+        .with_row(["Department", "Budget"])
+        .with_row(["Sales", "90000"])
+}
+
+#[test]
+fn render_into_io() {
+    let table = sample_table();
+    let console = Console(Decor::default().suppress_escape_codes());
+    let mut out = Vec::new();
+    console.render_into_io(&table, &[], &mut out).unwrap();
+    assert_eq!(console.render(&table).as_bytes(), out);
+
+    let mut out = Vec::new();
+    Markdown().render_into_io(&table, &[], &mut out).unwrap();
+    assert_eq!(Markdown().render(&table).as_bytes(), out);
+}
+
+#[test]
+fn render_into_io_error() {
+    struct Full;
+
+    impl io::Write for Full {
+        fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::WriteZero, "full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let err = Markdown().render_into_io(&sample_table(), &[], &mut Full).unwrap_err();
+    assert_eq!(io::ErrorKind::WriteZero, err.kind());
+}
@@ -0,0 +1,218 @@
+use crate::renderer::{RenderHint, Renderer, NEWLINE};
+use crate::style::{Header, Separator, Style};
+use crate::table::{Content, Spans, Table};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// Renders a [`Table`] as a JSON array.
+///
+/// If the table begins with one or more [`Header`] rows, they supply the field names and every
+/// subsequent row is emitted as an object. Otherwise, each row is emitted as an array of values.
+/// The field name of a column joins the text of its header cells, taking that of the spanning cell
+/// for a hidden one, and falls back to the index of the column if blank. A name that duplicates an
+/// earlier one is suffixed with `_2`, `_3` and so on, so that every object has distinct keys.
+/// Header rows following the first non-header row are emitted as objects like any other row.
+///
+/// Separator rows and columns are skipped and styles are ignored. Cells hidden by another cell's
+/// span are emitted as `null`. JSON has no notion of a caption, so the title and footer of the
+/// table are omitted.
+///
+/// Text content is emitted as JSON strings and nested tables as nested JSON arrays. A composite
+/// is emitted as a single string if all of its parts are textual; otherwise, it becomes an array of
+/// the values of its parts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Json {
+    pub pretty: bool,
+}
+
+impl Json {
+    /// Emits the entire document on a single line, without insignificant whitespace.
+    pub fn compact() -> Self {
+        Self { pretty: false }
+    }
+
+    /// Emits the document over multiple lines, indenting nested values.
+    pub fn pretty() -> Self {
+        Self { pretty: true }
+    }
+
+    fn render_table(&self, table: &Table, depth: usize, buf: &mut String) {
+        let cols = (0..table.num_cols())
+            .filter(|&col| !Separator::resolve_or_default(&table.col(col).blended_styles()).0)
+            .collect::<Vec<_>>();
+
+        let spans = table.spans();
+        let rows = (0..table.num_rows())
+            .filter(|&row| !Separator::resolve_or_default(&table.row(row).blended_styles()).0)
+            .collect::<Vec<_>>();
+        let header_rows = rows
+            .iter()
+            .take_while(|&&row| Header::resolve_or_default(&table.row(row).blended_styles()).0)
+            .count();
+        let field_names = (header_rows > 0)
+            .then(|| self.field_names(table, &spans, &cols, &rows[..header_rows]));
+        let rows = &rows[header_rows..];
+
+        self.open(buf, '[', rows.is_empty());
+        for (i, &row) in rows.iter().enumerate() {
+            self.separate(buf, i, depth + 1);
+            let (open, close) = if field_names.is_some() { ('{', '}') } else { ('[', ']') };
+            self.open(buf, open, cols.is_empty());
+            for (j, &col) in cols.iter().enumerate() {
+                self.separate(buf, j, depth + 2);
+                if let Some(field_names) = &field_names {
+                    append_string(&field_names[j], buf);
+                    buf.push(':');
+                    if self.pretty {
+                        buf.push(' ');
+                    }
+                }
+                match *table.cell(col, row) {
+                    Some(cell) if spans.is_origin(col, row) => {
+                        self.render_content(cell.data(), depth + 2, buf);
+                    }
+                    _ => buf.push_str("null"),
+                }
+            }
+            self.close(buf, close, cols.is_empty(), depth + 1);
+        }
+        self.close(buf, ']', rows.is_empty(), depth);
+    }
+
+    /// Derives a distinct field name for each of the given columns from the given header rows.
+    fn field_names(
+        &self,
+        table: &Table,
+        spans: &Spans,
+        cols: &[usize],
+        header_rows: &[usize],
+    ) -> Vec<String> {
+        let mut field_names: Vec<String> = Vec::with_capacity(cols.len());
+        for &col in cols {
+            let mut name = String::new();
+            for &row in header_rows {
+                let (origin_col, origin_row) = spans.origin(col, row);
+                if let Some(cell) = *table.cell(origin_col, origin_row) {
+                    let text = cell.data().render(self);
+                    if !text.is_empty() {
+                        if !name.is_empty() {
+                            name.push(' ');
+                        }
+                        name.push_str(&text);
+                    }
+                }
+            }
+            if name.is_empty() {
+                name = col.to_string();
+            }
+
+            let mut unique = name.clone();
+            let mut occurrence = 1;
+            while field_names.contains(&unique) {
+                occurrence += 1;
+                unique = format!("{name}_{occurrence}");
+            }
+            field_names.push(unique);
+        }
+        field_names
+    }
+
+    fn render_content(&self, content: &Content, depth: usize, buf: &mut String) {
+        match content {
+            Content::Label(s) => append_string(s, buf),
+            Content::Computed(f) => append_string(&f(), buf),
+            Content::Nested(table) => self.render_table(table, depth, buf),
+            Content::Composite(contents) => {
+                if contents.iter().any(Content::contains_nested) {
+                    self.open(buf, '[', contents.is_empty());
+                    for (i, content) in contents.iter().enumerate() {
+                        self.separate(buf, i, depth + 1);
+                        self.render_content(content, depth + 1, buf);
+                    }
+                    self.close(buf, ']', contents.is_empty(), depth);
+                } else {
+                    append_string(&content.render(self), buf);
+                }
+            }
+        }
+    }
+
+    fn open(&self, buf: &mut String, bracket: char, empty: bool) {
+        buf.push(bracket);
+        if self.pretty && !empty {
+            buf.push_str(NEWLINE);
+        }
+    }
+
+    fn separate(&self, buf: &mut String, index: usize, depth: usize) {
+        if index > 0 {
+            buf.push(',');
+            if self.pretty {
+                buf.push_str(NEWLINE);
+            }
+        }
+        self.indent(buf, depth);
+    }
+
+    fn close(&self, buf: &mut String, bracket: char, empty: bool, depth: usize) {
+        if self.pretty && !empty {
+            buf.push_str(NEWLINE);
+            self.indent(buf, depth);
+        }
+        buf.push(bracket);
+    }
+
+    fn indent(&self, buf: &mut String, depth: usize) {
+        if self.pretty {
+            for _ in 0..depth {
+                buf.push_str("  ");
+            }
+        }
+    }
+}
+
+impl Renderer for Json {
+    type Output = String;
+
+    fn render_with_hints(&self, table: &Table, _: &[RenderHint]) -> Self::Output {
+        let mut buf = String::new();
+        self.render_table(table, 0, &mut buf);
+        buf
+    }
+}
+
+impl Content {
+    fn contains_nested(&self) -> bool {
+        match self {
+            Content::Label(_) | Content::Computed(_) => false,
+            Content::Nested(_) => true,
+            Content::Composite(contents) => contents.iter().any(Content::contains_nested),
+        }
+    }
+}
+
+/// Appends the given string to the buffer as a quoted JSON string literal.
+fn append_string(s: &str, buf: &mut String) {
+    buf.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            '\u{08}' => buf.push_str("\\b"),
+            '\u{0c}' => buf.push_str("\\f"),
+            ch if ch < ' ' => {
+                write!(buf, "\\u{:04x}", ch as u32).unwrap();
+            }
+            ch => buf.push(ch),
+        }
+    }
+    buf.push('"');
+}
+
+#[cfg(test)]
+mod tests;
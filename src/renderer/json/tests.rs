@@ -0,0 +1,86 @@
+use crate::renderer::json::Json;
+use crate::renderer::Renderer;
+use crate::style::{ColSpan, Header, Styles};
+use crate::table::{Cell, Col, Content, Row, Table};
+use alloc::string::String;
+use alloc::vec;
+
+#[test]
+fn append_string() {
+    let mut buf = String::new();
+    super::append_string("say \"hi\"\\\n\t\u{01}", &mut buf);
+    assert_eq!(r#""say \"hi\"\\\n\t\u0001""#, buf);
+}
+
+#[test]
+fn render_objects() {
+    let table = Table::default()
+        .with_cols(vec![Col::default(), Col::separator(), Col::default()])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Department".into(), "".into(), "Budget".into()],
+        ))
+        .with_row(Row::separator())
+        .with_row(["Sales", "", "90000"])
+        .with_row(["Engineering"]);
+
+    assert_eq!(
+        r#"[{"Department":"Sales","Budget":"90000"},{"Department":"Engineering","Budget":null}]"#,
+        Json::compact().render(&table)
+    );
+    assert_eq!(
+        "[\n  {\n    \"Department\": \"Sales\",\n    \"Budget\": \"90000\"\n  },\n  \
+        {\n    \"Department\": \"Engineering\",\n    \"Budget\": null\n  }\n]",
+        Json::pretty().render(&table)
+    );
+}
+
+#[test]
+fn render_arrays_with_nested() {
+    let inner = Table::default().with_row(["a", "b"]);
+    let table = Table::default()
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                "x".into(),
+                inner.into(),
+                Content::Composite(vec!["y".into(), "z".into()]).into(),
+            ],
+        ));
+
+    assert_eq!(
+        r#"[["x",[["a","b"]],"yz"]]"#,
+        Json::compact().render(&table)
+    );
+}
+
+#[test]
+fn render_objects_with_duplicate_and_multi_row_headers() {
+    let table = Table::default()
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec![
+                "Name".into(),
+                Cell::new(Styles::default().with(ColSpan(2)), "Sales".into()),
+                "".into(),
+                "".into(),
+            ],
+        ))
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["".into(), "Q1".into(), "Q1".into(), "Name".into()],
+        ))
+        .with_row(["East", "1", "2", "x"])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["West".into(), "3".into(), "4".into(), "y".into()],
+        ));
+
+    assert_eq!(
+        concat!(
+            r#"[{"Name":"East","Sales Q1":"1","Sales Q1_2":"2","Name_2":"x"},"#,
+            r#"{"Name":"West","Sales Q1":"3","Sales Q1_2":"4","Name_2":"y"}]"#
+        ),
+        Json::compact().render(&table)
+    );
+}
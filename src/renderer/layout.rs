@@ -0,0 +1,896 @@
+//! The intermediate form of a table, between the table model and the output of a renderer.
+//!
+//! A [`Layout`] resolves everything that a text-based renderer needs to know before it can start
+//! drawing: the widths of the columns, the content of each cell wrapped to those widths, the
+//! blended styles of each cell, the heights of the rows, and the line style of every border
+//! segment. Custom renderers may use it to avoid reimplementing these calculations.
+
+use crate::renderer::width::display_width;
+use crate::renderer::{
+    content_width, fit_text, footnote_marker, pad_cell, vertical_offset, Fit, RenderHint, Renderer,
+    Viewport, ViewportWidths,
+};
+use crate::style::{
+    BorderBottom, BorderLeft, BorderRight, BorderTop, Header, MaxWidth, MinWidth, Overflow,
+    PaddingLeft, PaddingRight, Separator, Style, Styles, VAlign,
+};
+use crate::table::{Footnote, Spans, Table};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem;
+use core::ops::Range;
+
+pub use crate::style::Line;
+
+/// The characteristics of the borders drawn by a renderer, which bear on the layout of the table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Geometry {
+    /// Whether a cell spanning several columns or rows occupies the combined area of its span. If
+    /// not, the cell is confined to its own slot, and the remaining slots of its span are blank.
+    pub merge_spans: bool,
+
+    /// The combined width of the left and right outer borders. Adjacent columns are always assumed
+    /// to be separated by a single character.
+    pub outer_width: usize,
+
+    /// The line drawn around header rows and columns, unless a border style is assigned.
+    pub header_line: Line,
+
+    /// The number of lines separating adjacent rows.
+    pub row_separator_lines: usize,
+
+    /// The number of lines separating a header row from an adjacent row that isn't a header.
+    pub header_separator_lines: usize,
+}
+
+impl Geometry {
+    /// The number of characters taken up by borders in a table of the given number of columns.
+    pub fn overhead(&self, cols: usize) -> usize {
+        cols.saturating_sub(1) + self.outer_width
+    }
+}
+
+/// A table laid out for rendering in fixed-width text.
+#[derive(Clone)]
+pub struct Layout {
+    table_cols: Vec<usize>,
+    table_rows: Vec<usize>,
+    col_widths: Vec<usize>,
+    cells: Vec<Vec<LayoutCell>>,
+    col_styles: Vec<Styles>,
+    row_styles: Vec<Styles>,
+    header_cols: Vec<bool>,
+    header_rows: Vec<bool>,
+    separator_cols: Vec<bool>,
+    separator_rows: Vec<bool>,
+    spans: Spans,
+    merge_spans: bool,
+    header_line: Line,
+    vertical_lines: Vec<Vec<Line>>,
+    horizontal_lines: Vec<Vec<Line>>,
+    row_heights: Vec<usize>,
+    row_offsets: Vec<usize>,
+    separator_lines: Vec<usize>,
+    footnotes: Vec<Footnote>,
+}
+
+/// The laid out content of a single cell.
+#[derive(Clone)]
+pub struct LayoutCell {
+    /// The lines of content, wrapped or truncated to the width of the cell and surrounded by its
+    /// padding. The lines are not aligned horizontally; that is left to the renderer.
+    pub lines: Vec<String>,
+
+    /// The blended styles of the cell.
+    pub styles: Styles,
+
+    /// The number of blank lines above the content, as determined by its vertical alignment.
+    pub offset: usize,
+}
+
+impl LayoutCell {
+    /// The line of content at the given index, counting from the top of the cell, or a blank line
+    /// if the content is vertically aligned away from that line.
+    pub fn line(&self, index: usize) -> &str {
+        index
+            .checked_sub(self.offset)
+            .and_then(|index| self.lines.get(index))
+            .map_or("", |line| &line[..])
+    }
+}
+
+impl Layout {
+    /// Lays out the given table, rendering the content of each cell exactly once. The column
+    /// widths are measured from the content, unless given by a [`RenderHint::ColWidths`] hint, and
+    /// honour the [`RenderHint::FitWidth`] hint, if one is given, in which case some of the
+    /// rightmost columns may be dropped. Given a [`RenderHint::Viewport`] hint, only the rows and
+    /// columns in view are laid out, and they are indexed by their position within the viewport.
+    pub fn new(
+        table: &Table,
+        renderer: &impl Renderer,
+        hints: &[RenderHint],
+        geometry: &Geometry,
+    ) -> Self {
+        let (cols, rows) = in_view(table, RenderHint::viewport(hints));
+        let (content, col_widths) = measure(table, renderer, hints, geometry, &cols, &rows);
+
+        // if columns were dropped to fit the target width, only the leftmost columns are laid out
+        let num_cols = col_widths.len();
+        let (spans, mut col_styles, content_rows) = content.into_parts();
+        col_styles.truncate(num_cols);
+        let row_styles = rows
+            .iter()
+            .map(|&row| table.row(row).blended_styles())
+            .collect::<Vec<_>>();
+        let flags = |styles: &[Styles], flag: fn(&Styles) -> bool| {
+            styles.iter().map(flag).collect()
+        };
+        let mut layout = Self {
+            table_cols: cols[..num_cols].to_vec(),
+            table_rows: rows,
+            col_widths,
+            cells: Vec::new(),
+            header_cols: flags(&col_styles, |styles| Header::resolve_or_default(styles).0),
+            header_rows: flags(&row_styles, |styles| Header::resolve_or_default(styles).0),
+            separator_cols: flags(&col_styles, |styles| Separator::resolve_or_default(styles).0),
+            separator_rows: flags(&row_styles, |styles| Separator::resolve_or_default(styles).0),
+            col_styles,
+            row_styles,
+            spans,
+            merge_spans: geometry.merge_spans,
+            header_line: geometry.header_line,
+            vertical_lines: Vec::new(),
+            horizontal_lines: Vec::new(),
+            row_heights: Vec::new(),
+            row_offsets: Vec::new(),
+            separator_lines: Vec::new(),
+            footnotes: Vec::new(),
+        };
+        layout.fit_cells(content_rows);
+        layout.resolve_lines();
+        layout.settle_rows(geometry);
+        layout
+    }
+
+    /// Fits the rendered content of each cell to the width of its span, collecting the footnotes
+    /// of the cells along the way. Columns dropped to fit the target width are discarded.
+    fn fit_cells(&mut self, content_rows: Vec<Vec<RenderedCell<'_>>>) {
+        let num_cols = self.num_cols();
+        let mut footnotes = Vec::new();
+        self.cells = content_rows
+            .into_iter()
+            .enumerate()
+            .map(|(row, content_cells)| {
+                content_cells
+                    .into_iter()
+                    .take(num_cols)
+                    .enumerate()
+                    .map(|(col, content_cell)| {
+                        let span_cols = self.span_cols(col, row);
+                        if span_cols == 0 {
+                            // hidden by another cell's span
+                            return LayoutCell {
+                                lines: Vec::new(),
+                                styles: Styles::default(),
+                                offset: 0,
+                            };
+                        }
+                        let marker = content_cell.marker();
+                        let RenderedCell {
+                            data,
+                            styles,
+                            footnote,
+                        } = content_cell;
+                        let width = self.spanned_width(col, span_cols);
+                        let overflow = Overflow::resolve_or_default(&styles);
+                        let inner_width = content_width(width, &styles);
+                        let lines = match marker {
+                            None => fit_text(&data, inner_width, &overflow),
+                            Some(marker) => fit_marked_text(&data, &marker, inner_width, &overflow),
+                        };
+                        let lines = pad_cell(lines, width, &styles);
+                        if let Some(footnote) = footnote {
+                            footnotes.push(Footnote { col, row, ..footnote });
+                        }
+                        LayoutCell {
+                            lines,
+                            styles,
+                            offset: 0,
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        self.footnotes = footnotes;
+    }
+
+    /// Resolves the lines of the borders once, as they are looked up for every line drawn.
+    fn resolve_lines(&mut self) {
+        let (num_cols, num_rows) = (self.num_cols(), self.num_rows());
+        self.vertical_lines = (0..num_rows)
+            .map(|row| {
+                (0..num_cols.saturating_sub(1))
+                    .map(|col| self.resolve_vertical_line(col, row))
+                    .collect()
+            })
+            .collect();
+        self.horizontal_lines = (0..num_rows.saturating_sub(1))
+            .map(|row| {
+                (0..num_cols)
+                    .map(|col| self.resolve_horizontal_line(col, row))
+                    .collect()
+            })
+            .collect();
+    }
+
+    /// Settles the height of each row and the lines separating it from the next, and vertically
+    /// aligns the content of each cell within its span.
+    fn settle_rows(&mut self, geometry: &Geometry) {
+        let (num_cols, num_rows) = (self.num_cols(), self.num_rows());
+
+        // the height of each row is determined by its tallest cell that doesn't span multiple rows
+        let mut row_heights = (0..num_rows)
+            .map(|row| {
+                (0..num_cols)
+                    .filter(|&col| self.span_rows(col, row) == 1)
+                    .map(|col| self.cells[row][col].lines.len())
+                    .max()
+                    .unwrap_or(1)
+            })
+            .collect::<Vec<_>>();
+
+        // the lines of the border below each row, the last excepted
+        let separator_lines = (0..num_rows)
+            .map(|row| {
+                if row == num_rows - 1 {
+                    0
+                } else if (0..num_cols).all(|col| {
+                    !self.is_merged_vertically(col, row)
+                        && self.horizontal_line(col, row) == Line::None
+                }) {
+                    // a border without lines is omitted
+                    0
+                } else if self.is_header_row(row) != self.is_header_row(row + 1) {
+                    geometry.header_separator_lines
+                } else {
+                    geometry.row_separator_lines
+                }
+            })
+            .collect::<Vec<_>>();
+        let border_lines = |rows: Range<usize>| -> usize {
+            separator_lines[rows.start..rows.end - 1].iter().sum()
+        };
+
+        // rows are then heightened, if necessary, to accommodate cells spanning multiple rows; the
+        // border between adjacent rows also accommodates a line of the spanning cell
+        for row in 0..num_rows {
+            for (col, cell) in self.cells[row].iter().enumerate() {
+                let span_rows = self.span_rows(col, row);
+                if span_rows > 1 {
+                    let rows = row..row + span_rows;
+                    let available =
+                        row_heights[rows.clone()].iter().sum::<usize>() + border_lines(rows);
+                    let needed = cell.lines.len();
+                    if needed > available {
+                        row_heights[row + span_rows - 1] += needed - available;
+                    }
+                }
+            }
+        }
+
+        let mut row_offsets = Vec::with_capacity(row_heights.len());
+        let mut offset = 0;
+        for (&height, &lines) in row_heights.iter().zip(&separator_lines) {
+            row_offsets.push(offset);
+            offset += height + lines;
+        }
+
+        // with the row heights settled, vertically align the content of each cell within its span
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                let span_rows = self.span_rows(col, row);
+                if span_rows > 0 {
+                    let rows = row..row + span_rows;
+                    let height =
+                        row_heights[rows.clone()].iter().sum::<usize>() + border_lines(rows);
+                    let cell = &mut self.cells[row][col];
+                    let alignment = VAlign::resolve_or_default(&cell.styles);
+                    cell.offset = vertical_offset(cell.lines.len(), height, &alignment);
+                }
+            }
+        }
+
+        self.row_heights = row_heights;
+        self.row_offsets = row_offsets;
+        self.separator_lines = separator_lines;
+    }
+
+    /// The widths of the laid out columns, which excludes any columns dropped to fit the target
+    /// width.
+    pub fn col_widths(&self) -> &[usize] {
+        &self.col_widths
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.col_widths.len()
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.row_styles.len()
+    }
+
+    /// The cell originating in the given slot. A slot that is hidden by another cell's span holds
+    /// an empty cell.
+    pub fn cell(&self, col: usize, row: usize) -> &LayoutCell {
+        &self.cells[row][col]
+    }
+
+    /// Grants mutable access to the laid out content of the cell at the given slot, so that a
+    /// renderer may substitute content of its own making. The replacement lines shouldn't exceed
+    /// the width or height of the cell's span. The border lines are resolved as the table is laid
+    /// out, and so don't reflect any changes to the border styles of the cell.
+    pub fn cell_mut(&mut self, col: usize, row: usize) -> &mut LayoutCell {
+        &mut self.cells[row][col]
+    }
+
+    /// The index within the table of the laid out column.
+    pub fn table_col(&self, col: usize) -> usize {
+        self.table_cols[col]
+    }
+
+    /// The index within the table of the laid out row.
+    pub fn table_row(&self, row: usize) -> usize {
+        self.table_rows[row]
+    }
+
+    /// Widens the rightmost column by the given number of characters. The content of its cells
+    /// isn't refitted, as the lines laid out for the narrower column also fit the wider one.
+    pub fn widen(&mut self, extra: usize) {
+        if let Some(width) = self.col_widths.last_mut() {
+            *width += extra;
+        }
+    }
+
+    pub fn col_styles(&self, col: usize) -> &Styles {
+        &self.col_styles[col]
+    }
+
+    pub fn row_styles(&self, row: usize) -> &Styles {
+        &self.row_styles[row]
+    }
+
+    /// The footnotes of the cells that are laid out, in reading order. Each footnote retains its
+    /// number within the table, but is located by its position within the layout.
+    pub fn footnotes(&self) -> &[Footnote] {
+        &self.footnotes
+    }
+
+    /// The number of lines occupied by the given row, excluding the border below it.
+    pub fn row_height(&self, row: usize) -> usize {
+        self.row_heights[row]
+    }
+
+    /// The number of lines separating the given row from the next, or zero for the last row.
+    pub fn separator_lines(&self, row: usize) -> usize {
+        self.separator_lines[row]
+    }
+
+    /// The width of a region spanning the given columns, including the borders between them.
+    pub fn spanned_width(&self, col: usize, span_cols: usize) -> usize {
+        self.col_widths[col..col + span_cols].iter().sum::<usize>() + span_cols - 1
+    }
+
+    pub fn is_header_col(&self, col: usize) -> bool {
+        self.header_cols[col]
+    }
+
+    pub fn is_header_row(&self, row: usize) -> bool {
+        self.header_rows[row]
+    }
+
+    pub fn is_separator_col(&self, col: usize) -> bool {
+        self.separator_cols[col]
+    }
+
+    pub fn is_separator_row(&self, row: usize) -> bool {
+        self.separator_rows[row]
+    }
+
+    /// The slot in which the cell occupying the given slot originates. When spans aren't merged,
+    /// every slot is its own origin.
+    pub fn origin(&self, col: usize, row: usize) -> (usize, usize) {
+        if self.merge_spans {
+            self.spans.origin(col, row)
+        } else {
+            (col, row)
+        }
+    }
+
+    /// The number of laid out columns occupied by the cell originating in the given slot, or zero
+    /// if the slot is hidden by another cell's span. When spans aren't merged, every slot occupies
+    /// exactly one column.
+    pub fn span_cols(&self, col: usize, row: usize) -> usize {
+        if self.merge_spans {
+            usize::min(self.spans.extent(col, row).0, self.num_cols() - col)
+        } else {
+            1
+        }
+    }
+
+    /// The number of rows occupied by the cell originating in the given slot, or zero if the slot
+    /// is hidden by another cell's span. When spans aren't merged, every slot occupies exactly one
+    /// row.
+    pub fn span_rows(&self, col: usize, row: usize) -> usize {
+        if self.merge_spans {
+            self.spans.extent(col, row).1
+        } else {
+            1
+        }
+    }
+
+    /// Whether the slots at `col` and `col + 1` in the given row belong to the same cell.
+    pub fn is_merged_horizontally(&self, col: usize, row: usize) -> bool {
+        self.origin(col, row) == self.origin(col + 1, row)
+    }
+
+    /// Whether the slots at `row` and `row + 1` in the given column belong to the same cell.
+    pub fn is_merged_vertically(&self, col: usize, row: usize) -> bool {
+        self.origin(col, row) == self.origin(col, row + 1)
+    }
+
+    /// The line style of the border between `col` and `col + 1` in the given row.
+    pub fn vertical_line(&self, col: usize, row: usize) -> Line {
+        self.vertical_lines[row][col]
+    }
+
+    /// The line style of the border between `row` and `row + 1` in the given column.
+    pub fn horizontal_line(&self, col: usize, row: usize) -> Line {
+        self.horizontal_lines[row][col]
+    }
+
+    fn resolve_vertical_line(&self, col: usize, row: usize) -> Line {
+        if self.is_merged_horizontally(col, row) {
+            Line::None
+        } else if let Some(line) = assigned_line(
+            BorderRight::resolve(self.origin_styles(col, row)).map(|border| border.0),
+            BorderLeft::resolve(self.origin_styles(col + 1, row)).map(|border| border.0),
+        ) {
+            line
+        } else if self.is_header_col(col) || self.is_header_col(col + 1) {
+            self.header_line
+        } else if self.is_separator_row(row) {
+            Line::None
+        } else {
+            Line::Thin
+        }
+    }
+
+    fn resolve_horizontal_line(&self, col: usize, row: usize) -> Line {
+        if self.is_merged_vertically(col, row) {
+            Line::None
+        } else if let Some(line) = assigned_line(
+            BorderBottom::resolve(self.origin_styles(col, row)).map(|border| border.0),
+            BorderTop::resolve(self.origin_styles(col, row + 1)).map(|border| border.0),
+        ) {
+            line
+        } else if self.is_header_row(row) || self.is_header_row(row + 1) {
+            self.header_line
+        } else if self.is_separator_col(col) {
+            Line::None
+        } else {
+            Line::Thin
+        }
+    }
+
+    /// The blended styles of the cell occupying the given slot.
+    fn origin_styles(&self, col: usize, row: usize) -> &Styles {
+        let (origin_col, origin_row) = self.origin(col, row);
+        &self.cells[origin_row][origin_col].styles
+    }
+
+    /// The line styles of the arms meeting at the junction between columns `col` and `col + 1`
+    /// and rows `row` and `row + 1`, in the order `[up, right, down, left]`.
+    pub fn junction(&self, col: usize, row: usize) -> [Line; 4] {
+        [
+            self.vertical_line(col, row),
+            self.horizontal_line(col + 1, row),
+            self.vertical_line(col, row + 1),
+            self.horizontal_line(col, row),
+        ]
+    }
+
+    /// The index of the first line of `row` within the lines of a cell that originates in
+    /// `origin_row`.
+    pub fn line_of(&self, origin_row: usize, row: usize) -> usize {
+        self.row_offsets[row] - self.row_offsets[origin_row]
+    }
+}
+
+/// The columns and rows of the table in view, in ascending order. Without a viewport, every column
+/// and row is in view; otherwise, header columns and rows are kept in view alongside those within
+/// the viewport, [clamped](clamp) to the table.
+fn in_view(table: &Table, viewport: Option<&Viewport>) -> (Vec<usize>, Vec<usize>) {
+    let Some(viewport) = viewport else {
+        return ((0..table.num_cols()).collect(), (0..table.num_rows()).collect());
+    };
+    let viewport_cols = clamp(&viewport.cols, table.num_cols());
+    let cols = (0..table.num_cols())
+        .filter(|&col| {
+            viewport_cols.contains(&col)
+                || Header::resolve_or_default(&table.col(col).blended_styles()).0
+        })
+        .collect();
+    let viewport_rows = clamp(&viewport.rows, table.num_rows());
+    let rows = (0..table.num_rows())
+        .filter(|&row| {
+            viewport_rows.contains(&row)
+                || Header::resolve_or_default(&table.row(row).blended_styles()).0
+        })
+        .collect();
+    (cols, rows)
+}
+
+/// Clamps a range of the viewport to the given number of columns or rows, so that it takes in at
+/// least one of them. An empty range is widened to one column or row, and a range lying beyond the
+/// last column or row is moved back to end with it, as happens when scrolling past the end of a
+/// table.
+fn clamp(range: &Range<usize>, len: usize) -> Range<usize> {
+    let size = usize::max(range.len(), 1);
+    let start = if range.start < len {
+        range.start
+    } else {
+        len.saturating_sub(size)
+    };
+    start..start + size
+}
+
+/// Renders the cells in view, and resolves the widths of their columns: given by the
+/// [`RenderHint::ColWidths`] hint, if any, or else measured from the content of either the table
+/// or the window in view, as chosen by the viewport. The widths are then fitted as per the
+/// [`RenderHint::FitWidth`] hint, if any.
+fn measure<'a>(
+    table: &'a Table,
+    renderer: &impl Renderer,
+    hints: &[RenderHint],
+    geometry: &Geometry,
+    cols: &[usize],
+    rows: &[usize],
+) -> (RenderedCells<'a>, Vec<usize>) {
+    let viewport = RenderHint::viewport(hints);
+    let (content, natural_widths) = match (viewport, RenderHint::col_widths(hints)) {
+        (_, Some(given)) => {
+            // the widths are given, so the cells outside the viewport needn't be rendered
+            let content = RenderedCells::window(table, renderer, cols, rows);
+            let natural_widths = cols
+                .iter()
+                .map(|&col| given.get(col).copied().unwrap_or(0))
+                .collect();
+            (content, natural_widths)
+        }
+        (None, None) => {
+            let content = RenderedCells::new(table, renderer);
+            let natural_widths = content.natural_col_widths(geometry);
+            (content, natural_widths)
+        }
+        (Some(Viewport { widths: ViewportWidths::Table, .. }), None) => {
+            let content = RenderedCells::new(table, renderer);
+            let table_widths = content.natural_col_widths(geometry);
+            let natural_widths = cols.iter().map(|&col| table_widths[col]).collect();
+            (content.into_window(cols, rows), natural_widths)
+        }
+        (Some(Viewport { widths: ViewportWidths::Window, .. }), None) => {
+            let content = RenderedCells::window(table, renderer, cols, rows);
+            let natural_widths = content.natural_col_widths(geometry);
+            (content, natural_widths)
+        }
+    };
+    let fitted_widths = match RenderHint::fit_width(hints) {
+        None => natural_widths,
+        Some((target, fit)) => content.fit_col_widths(natural_widths, target, fit, |cols| {
+            geometry.overhead(cols)
+        }),
+    };
+    (content, fitted_widths)
+}
+
+/// The line assigned to the border between two adjacent cells by their border styles, if any. A
+/// line prevails over [`Line::None`]; otherwise, the latter cell prevails.
+fn assigned_line(former: Option<Line>, latter: Option<Line>) -> Option<Line> {
+    match (former, latter) {
+        (Some(former), Some(Line::None)) => Some(former),
+        (_, Some(latter)) => Some(latter),
+        (former, None) => former,
+    }
+}
+
+/// Fits the text within the given width, less the width of the footnote marker, as per
+/// [`fit_text`], and appends the marker to the last line. The marker is thereby never separated
+/// from the text it annotates.
+fn fit_marked_text(s: &str, marker: &str, width: usize, overflow: &Overflow) -> Vec<String> {
+    let mut lines = fit_text(s, width.saturating_sub(display_width(marker)), overflow);
+    if let Some(line) = lines.last_mut() {
+        line.push_str(marker);
+    }
+    lines
+}
+
+/// The content of every cell in a table, rendered exactly once, alongside the blended styles of
+/// each cell and column.
+///
+/// Measurement and drawing both work from the rendered content, so that computed content is
+/// evaluated, and nested tables are laid out, only once per render.
+pub struct RenderedCells<'a> {
+    spans: Spans,
+    col_styles: Vec<Styles>,
+    rows: Vec<Vec<RenderedCell<'a>>>,
+}
+
+/// The rendered content and blended styles of a single cell, alongside its footnote. A cell that
+/// is missing, or hidden by another cell's span, has empty content.
+#[derive(Default)]
+pub struct RenderedCell<'a> {
+    pub data: Cow<'a, str>,
+    pub styles: Styles,
+    pub footnote: Option<Footnote>,
+}
+
+impl RenderedCell<'_> {
+    /// The marker referencing the footnote of the cell, if it has one.
+    pub fn marker(&self) -> Option<String> {
+        self.footnote
+            .as_ref()
+            .map(|footnote| footnote_marker(footnote.number))
+    }
+
+    /// The natural width of the cell, widened by its footnote marker, if any. The marker doesn't
+    /// count against the [`MaxWidth`] of the cell, so that the content wraps just as it would
+    /// without the marker.
+    fn width(&self) -> usize {
+        let marker_width = self.marker().map_or(0, |marker| display_width(&marker));
+        super::cell_width(&self.data, &self.styles) + marker_width
+    }
+}
+
+impl<'a> RenderedCells<'a> {
+    pub fn new(table: &'a Table, renderer: &impl Renderer) -> Self {
+        let cols = (0..table.num_cols()).collect::<Vec<_>>();
+        let rows = (0..table.num_rows()).collect::<Vec<_>>();
+        Self::window(table, renderer, &cols, &rows)
+    }
+
+    /// Renders only the cells within a window comprising the given columns and rows, each listed
+    /// in ascending order. Cells are indexed by their position within the window, as per
+    /// [`Spans::window`].
+    pub fn window(
+        table: &'a Table,
+        renderer: &impl Renderer,
+        cols: &[usize],
+        rows: &[usize],
+    ) -> Self {
+        let table_spans = table.spans();
+        let spans = table_spans.window(cols, rows);
+        let footnotes = table.footnotes();
+        let col_styles = cols
+            .iter()
+            .map(|&col| table.col(col).blended_styles())
+            .collect();
+        let rows = rows
+            .iter()
+            .enumerate()
+            .map(|(window_row, &row)| {
+                cols.iter()
+                    .enumerate()
+                    .map(|(window_col, &col)| {
+                        if spans.is_origin(window_col, window_row) {
+                            // the cell may originate outside the window
+                            let (origin_col, origin_row) = table_spans.origin(col, row);
+                            let cell = table.cell(origin_col, origin_row);
+                            let (data, text) = cell.map_or(
+                                (Cow::Borrowed(""), Cow::Borrowed("")),
+                                |cell| cell.data().render_with_text(renderer),
+                            );
+                            let footnote = footnotes
+                                .binary_search_by_key(&(origin_row, origin_col), |footnote| {
+                                    (footnote.row, footnote.col)
+                                })
+                                .ok()
+                                .map(|index| footnotes[index].clone());
+                            RenderedCell {
+                                data,
+                                styles: cell.with_text(&text).blended_styles(),
+                                footnote,
+                            }
+                        } else {
+                            RenderedCell {
+                                data: Cow::Borrowed(""),
+                                styles: table.cell(col, row).blended_styles(),
+                                footnote: None,
+                            }
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            spans,
+            col_styles,
+            rows,
+        }
+    }
+
+    /// Projects the rendered cells onto a window, as per [`RenderedCells::window`], reusing the
+    /// content that has already been rendered.
+    #[must_use]
+    pub fn into_window(mut self, cols: &[usize], rows: &[usize]) -> Self {
+        let spans = self.spans.window(cols, rows);
+        let col_styles = cols
+            .iter()
+            .map(|&col| mem::take(&mut self.col_styles[col]))
+            .collect();
+        let rows = rows
+            .iter()
+            .enumerate()
+            .map(|(window_row, &row)| {
+                cols.iter()
+                    .enumerate()
+                    .map(|(window_col, &col)| {
+                        if spans.is_origin(window_col, window_row) {
+                            let (origin_col, origin_row) = self.spans.origin(col, row);
+                            mem::take(&mut self.rows[origin_row][origin_col])
+                        } else {
+                            RenderedCell {
+                                data: Cow::Borrowed(""),
+                                styles: mem::take(&mut self.rows[row][col].styles),
+                                footnote: None,
+                            }
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            spans,
+            col_styles,
+            rows,
+        }
+    }
+
+    pub fn spans(&self) -> &Spans {
+        &self.spans
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.col_styles.len()
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn cell(&self, col: usize, row: usize) -> &RenderedCell<'a> {
+        &self.rows[row][col]
+    }
+
+    /// Decomposes the rendered table into its spans, the blended styles of its columns and its
+    /// cells, indexed by row and then column.
+    pub fn into_parts(self) -> (Spans, Vec<Styles>, Vec<Vec<RenderedCell<'a>>>) {
+        (self.spans, self.col_styles, self.rows)
+    }
+
+    /// Calculates the width of a single column, as per [`Table::col_width`].
+    pub fn col_width(&self, col: usize) -> usize {
+        (0..self.num_rows())
+            .map(|row| {
+                let cell = self.cell(col, row);
+                if self.spans.extent(col, row).0 == 1 {
+                    cell.width()
+                } else {
+                    // hidden and multi-column cells don't contribute to the width of a single
+                    // column; however, the MinWidth constraint still applies
+                    MinWidth::resolve_or_default(&cell.styles).0
+                }
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Calculates the widths of all columns, as per [`Table::col_widths`].
+    pub fn col_widths(&self) -> Vec<usize> {
+        let mut widths = (0..self.num_cols())
+            .map(|col| self.col_width(col))
+            .collect::<Vec<_>>();
+        let max_widths = self
+            .col_styles
+            .iter()
+            .map(|styles| MaxWidth::resolve_or_default(styles).0)
+            .collect::<Vec<_>>();
+
+        for row in 0..self.num_rows() {
+            for col in 0..self.num_cols() {
+                let (span_cols, _) = self.spans.extent(col, row);
+                if span_cols < 2 {
+                    continue;
+                }
+
+                let cols = col..col + span_cols;
+                let cell = self.cell(col, row);
+                let needed = cell.width();
+                let mut available = widths[cols.clone()].iter().sum::<usize>() + span_cols - 1;
+                while available < needed {
+                    let mut widened = false;
+                    for c in cols.clone() {
+                        if available < needed && widths[c] < max_widths[c] {
+                            widths[c] += 1;
+                            available += 1;
+                            widened = true;
+                        }
+                    }
+                    if !widened {
+                        break;
+                    }
+                }
+            }
+        }
+        widths
+    }
+
+    /// Calculates the widths of the columns for a renderer with the given geometry. If the geometry
+    /// doesn't merge spans, each cell is confined to its own slot, and so contributes its entire
+    /// width to the column in which it originates.
+    pub fn natural_col_widths(&self, geometry: &Geometry) -> Vec<usize> {
+        if geometry.merge_spans {
+            return self.col_widths();
+        }
+        (0..self.num_cols())
+            .map(|col| {
+                (0..self.num_rows())
+                    .map(|row| self.cell(col, row).width())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    /// Calculates the widths of the columns fitted to the target width, as per
+    /// [`Table::fitted_col_widths`].
+    pub fn fitted_col_widths(
+        &self,
+        target: usize,
+        fit: Fit,
+        overhead: impl Fn(usize) -> usize,
+    ) -> Vec<usize> {
+        self.fit_col_widths(self.col_widths(), target, fit, overhead)
+    }
+
+    /// Shrinks or drops the given column widths to fit the target width, as per
+    /// [`Table::fitted_col_widths`].
+    pub fn fit_col_widths(
+        &self,
+        widths: Vec<usize>,
+        target: usize,
+        fit: Fit,
+        overhead: impl Fn(usize) -> usize,
+    ) -> Vec<usize> {
+        let min_width = |styles: &Styles| {
+            let padding = PaddingLeft::resolve_or_default(styles).0
+                + PaddingRight::resolve_or_default(styles).0;
+            usize::max(1, MinWidth::resolve_or_default(styles).0) + padding
+        };
+
+        // a column is never shrunk below the minimum width of any of its cells, other than those
+        // spanning multiple columns
+        let min_widths = (0..self.num_cols())
+            .map(|col| {
+                (0..self.num_rows())
+                    .filter(|&row| self.spans.extent(col, row).0 == 1)
+                    .map(|row| min_width(&self.cell(col, row).styles))
+                    .fold(min_width(&self.col_styles[col]), usize::max)
+            })
+            .collect::<Vec<_>>();
+        super::fit_widths(widths, &min_widths, target, fit, overhead)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,276 @@
+use crate::renderer::console::Console;
+use crate::renderer::layout::{Geometry, Layout, Line};
+use crate::renderer::{Fit, RenderHint, Renderer, Viewport, ViewportWidths};
+use crate::style::{
+    BorderBottom, BorderLeft, BorderTop, ColSpan, HAlign, Header, MaxWidth, MinWidth, PaddingLeft,
+    PaddingRight, Styles,
+};
+use crate::table::{Cell, Col, Row, Table};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+const MERGED: Geometry = Geometry {
+    merge_spans: true,
+    outer_width: 2,
+    header_line: Line::Bold,
+    row_separator_lines: 1,
+    header_separator_lines: 1,
+};
+
+fn sample_table() -> Table {
+    Table::default()
+        .with_cols(vec![
+            Col::new(Styles::default().with(MaxWidth(5))),
+            Col::default(),
+        ])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Name".into(), "Size".into()],
+        ))
+        .with_row(["Hello world", "12"])
+        .with_row(Row::new(
+            Styles::default(),
+            vec![Cell::new(Styles::default().with(ColSpan(2)).with(HAlign::Right), "total".into())],
+        ))
+}
+
+#[test]
+fn widths_and_lines() {
+    let layout = Layout::new(&sample_table(), &Console::default(), &[], &MERGED);
+    assert_eq!(&[5, 4], layout.col_widths());
+    assert_eq!(3, layout.num_rows());
+    assert_eq!(vec!["Hello", "world"], layout.cell(0, 1).lines);
+    assert_eq!(2, layout.row_height(1));
+    assert_eq!("", layout.cell(1, 1).line(1));
+    assert!(layout.is_header_row(0));
+}
+
+#[test]
+fn spans_and_junctions() {
+    let layout = Layout::new(&sample_table(), &Console::default(), &[], &MERGED);
+    assert_eq!(2, layout.span_cols(0, 2));
+    assert_eq!(0, layout.span_cols(1, 2));
+    assert_eq!((0, 2), layout.origin(1, 2));
+    assert_eq!(10, layout.spanned_width(0, 2));
+    assert_eq!(vec!["total"], layout.cell(0, 2).lines);
+
+    // below the header, and above the spanning cell
+    assert_eq!([Line::Thin, Line::Bold, Line::Thin, Line::Bold], layout.junction(0, 0));
+    assert_eq!([Line::Thin, Line::Thin, Line::None, Line::Thin], layout.junction(0, 1));
+
+    let unmerged = Geometry {
+        merge_spans: false,
+        ..MERGED
+    };
+    let layout = Layout::new(&sample_table(), &Console::default(), &[], &unmerged);
+    assert_eq!(1, layout.span_cols(1, 2));
+    assert_eq!((1, 2), layout.origin(1, 2));
+    assert_eq!(vec!["total"], layout.cell(0, 2).lines);
+    assert_eq!([Line::Thin, Line::Thin, Line::Thin, Line::Thin], layout.junction(0, 1));
+}
+
+#[test]
+fn unmerged_widths() {
+    let table = Table::default().with_row(["a", "b"]).with_row(Row::new(
+        Styles::default(),
+        vec![Cell::new(Styles::default().with(ColSpan(2)), "spanning".into())],
+    ));
+    let layout = Layout::new(&table, &Console::default(), &[], &MERGED);
+    assert_eq!(&[4, 3], layout.col_widths());
+
+    // confined to its own slot, the spanning cell widens only the column in which it originates
+    let unmerged = Geometry {
+        merge_spans: false,
+        ..MERGED
+    };
+    let layout = Layout::new(&table, &Console::default(), &[], &unmerged);
+    assert_eq!(&[8, 1], layout.col_widths());
+    assert_eq!(vec!["spanning"], layout.cell(0, 1).lines);
+}
+
+#[test]
+fn border_styles() {
+    let table = Table::default()
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["Item".into(), "Qty".into()],
+        ))
+        .with_row(Row::new(
+            Styles::default().with(BorderBottom(Line::None)),
+            vec!["Apples".into(), "3".into()],
+        ))
+        .with_row(Row::new(
+            Styles::default().with(BorderBottom(Line::Dashed)),
+            vec![
+                "Pears".into(),
+                Cell::new(Styles::default().with(BorderLeft(Line::Heavy)), "2".into()),
+            ],
+        ))
+        .with_row(Row::new(
+            Styles::default().with(BorderTop(Line::None)),
+            vec!["Total".into(), "5".into()],
+        ));
+    let layout = Layout::new(&table, &Console::default(), &[], &MERGED);
+
+    // borders without styles are drawn as before
+    assert_eq!(Line::Bold, layout.horizontal_line(0, 0));
+    assert_eq!(Line::Thin, layout.vertical_line(0, 1));
+    assert_eq!(Line::Heavy, layout.vertical_line(0, 2));
+
+    // a border without lines is omitted
+    assert_eq!(Line::None, layout.horizontal_line(1, 1));
+    assert_eq!(0, layout.separator_lines(1));
+    assert_eq!(1, layout.line_of(1, 2));
+
+    // a line prevails over the absence of one
+    assert_eq!(Line::Dashed, layout.horizontal_line(0, 2));
+    assert_eq!(1, layout.separator_lines(2));
+}
+
+#[test]
+fn fit_width() {
+    let hints = [RenderHint::FitWidth(8, Fit::DropRightmostCols)];
+    let layout = Layout::new(&sample_table(), &Console::default(), &hints, &MERGED);
+    assert_eq!(&[5], layout.col_widths());
+    assert_eq!(1, layout.span_cols(0, 2));
+}
+
+#[test]
+fn fit_width_honours_cell_min_width() {
+    // the padding of a cell adds to the minimum width of its column
+    let table = Table::default()
+        .with_cols(vec![Col::default(), Col::new(Styles::default().with(MinWidth(4)))])
+        .with_row(["twenty characters...", "twenty characters..."])
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                "".into(),
+                Cell::new(
+                    Styles::default().with(PaddingLeft(3)).with(PaddingRight(3)),
+                    "".into(),
+                ),
+            ],
+        ));
+    let hints = [RenderHint::FitWidth(17, Fit::Proportional)];
+    let layout = Layout::new(&table, &Console::default(), &hints, &MERGED);
+    assert_eq!(&[4, 10], layout.col_widths());
+}
+
+#[test]
+fn viewport() {
+    let mut table = Table::default()
+        .with_cols(vec![
+            Col::new(Styles::default().with(Header(true))),
+            Col::default(),
+            Col::default(),
+        ])
+        .with_row(Row::new(
+            Styles::default().with(Header(true)),
+            vec!["#".into(), "Name".into(), "Value".into()],
+        ));
+    for row in 1..=20 {
+        table.push_row(Row::new(
+            Styles::default(),
+            vec![row.to_string().into(), format!("item {row}").into(), "x".repeat(row).into()],
+        ));
+    }
+
+    let hints = [RenderHint::Viewport(Viewport::new(5..8, 2..3))];
+    let layout = Layout::new(&table, &Console::default(), &hints, &MERGED);
+    assert_eq!(4, layout.num_rows());
+    assert_eq!(2, layout.num_cols());
+    assert_eq!(
+        vec!["#", "5", "6", "7"],
+        (0..4).map(|row| layout.cell(0, row).line(0)).collect::<Vec<_>>()
+    );
+    assert_eq!("xxxxxx", layout.cell(1, 2).line(0));
+
+    // widths are stable across the table by default, or computed only within the window
+    assert_eq!(&[2, 20], layout.col_widths());
+    let hints = [RenderHint::Viewport(
+        Viewport::new(5..8, 2..3).with_widths(ViewportWidths::Window),
+    )];
+    let layout = Layout::new(&table, &Console::default(), &hints, &MERGED);
+    assert_eq!(&[1, 7], layout.col_widths());
+}
+
+#[test]
+fn viewport_beyond_table() {
+    #[derive(Debug)]
+    struct Case {
+        input: Viewport,
+        expect: Vec<Vec<&'static str>>,
+    }
+
+    let table = Table::default().with_row(["a", "b"]).with_row(["c", "d"]);
+    for case in [
+        // a window scrolled past the end of the table is moved back to end with it
+        Case {
+            input: Viewport::new(5..6, 0..2),
+            expect: vec![vec!["c", "d"]],
+        },
+        Case {
+            input: Viewport::new(5..7, 3..4),
+            expect: vec![vec!["b"], vec!["d"]],
+        },
+        // an empty window takes in a single row
+        Case {
+            input: Viewport::rows(1..1),
+            expect: vec![vec!["c", "d"]],
+        },
+        // a window overlapping the end of the table is left as is
+        Case {
+            input: Viewport::rows(1..5),
+            expect: vec![vec!["c", "d"]],
+        },
+    ] {
+        let hints = [RenderHint::Viewport(case.input.clone())];
+        let layout = Layout::new(&table, &Console::default(), &hints, &MERGED);
+        let cells = (0..layout.num_rows())
+            .map(|row| {
+                (0..layout.num_cols())
+                    .map(|col| layout.cell(col, row).line(0))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(case.expect, cells, "for {:?}", case);
+
+        // the window is drawn, as well as laid out
+        let rendered = Console::default().render_with_hints(&table, &hints);
+        for text in case.expect.iter().flatten() {
+            assert!(rendered.contains(text), "for {:?}", case);
+        }
+    }
+}
+
+#[test]
+fn footnotes() {
+    let table = Table::default()
+        .with_cols(vec![Col::new(Styles::default().with(MaxWidth(7))), Col::default()])
+        .with_row(Row::new(
+            Styles::default(),
+            vec![Cell::from("Revenue").with_footnote("Excludes tax."), "9000".into()],
+        ))
+        .with_row(Row::new(
+            Styles::default(),
+            vec!["Total revenue".into(), Cell::from("9000").with_footnote("Audited.")],
+        ));
+
+    // the marker widens the column beyond its MaxWidth, rather than wrapping the content
+    let layout = Layout::new(&table, &Console::default(), &[], &MERGED);
+    assert_eq!(&[8, 5], layout.col_widths());
+    assert_eq!(vec!["Revenue¹"], layout.cell(0, 0).lines);
+    assert_eq!(vec!["Total", "revenue"], layout.cell(0, 1).lines);
+    assert_eq!(vec!["9000²"], layout.cell(1, 1).lines);
+    assert_eq!(2, layout.footnotes().len());
+
+    // only the footnotes in view are laid out, located by their position within the viewport
+    let viewport = Viewport::rows(1..2);
+    let hints = [RenderHint::Viewport(viewport)];
+    let layout = Layout::new(&table, &Console::default(), &hints, &MERGED);
+    let footnote = &layout.footnotes()[0];
+    assert_eq!(1, layout.footnotes().len());
+    assert_eq!((1, 0, 2), (footnote.col, footnote.row, footnote.number));
+    assert_eq!("Audited.".to_string(), footnote.text);
+}
@@ -1,10 +1,12 @@
-use alloc::borrow::Cow;
 use alloc::string::String;
-use alloc::vec::Vec;
 use core::borrow::Borrow;
-use crate::table::Table;
-use crate::renderer::{pad, wrap, Renderer, NEWLINE, RenderHint};
-use crate::style::{HAlign, Style};
+use core::fmt;
+use core::fmt::Write;
+use crate::table::{Footnote, Table};
+use crate::renderer::layout::{Geometry, Layout, Line};
+use crate::renderer::pagination::Paginate;
+use crate::renderer::{footnote_marker, pad, Renderer, NEWLINE, RenderHint, StreamRenderer};
+use crate::style::{HAlign, Header, Style};
 
 #[derive(Default)]
 pub struct Markdown();
@@ -12,32 +14,111 @@ pub struct Markdown();
 impl Renderer for Markdown {
     type Output = String;
 
-    fn render_with_hints(&self, table: &Table, _: &[RenderHint]) -> Self::Output {
+    fn render_with_hints(&self, table: &Table, hints: &[RenderHint]) -> Self::Output {
+        let mut buf = String::new();
+        self.render_into(table, hints, &mut buf).unwrap();
+        buf
+    }
+}
+
+impl StreamRenderer for Markdown {
+    fn render_into(
+        &self,
+        table: &Table,
+        hints: &[RenderHint],
+        out: &mut impl Write,
+    ) -> fmt::Result {
         assert!(!table.is_empty(), "table cannot be empty");
-        let col_widths = table.col_widths(self);
+        let layout = Layout::new(
+            table,
+            self,
+            hints,
+            &self.geometry(),
+        );
         let mut buf = String::new();
 
-        // print the header
-        print_row(self, table, &col_widths, 0, &mut buf);
+        // print the title as a heading
+        if let Some(title) = *table.title() {
+            buf.push_str("### ");
+            buf.push_str(&escape(title.text()));
+            buf.push_str(NEWLINE);
+            buf.push_str(NEWLINE);
+        }
+
+        // print the header, being the first row of the table; Markdown tables cannot do without
+        // one, so a blank header is printed if the first row is hidden by a viewport
+        let body_start = if layout.table_row(0) == 0 {
+            print_row(&layout, 0, &mut buf);
+            1
+        } else {
+            print_blank_row(&layout, &mut buf);
+            0
+        };
 
         // print the line between the header and the body
-        print_header_format(table, &col_widths, &mut buf);
+        print_header_format(&layout, &mut buf);
+        out.write_str(&buf)?;
 
-        // print the body
-        for row in 1..table.num_rows() {
-            print_row(self, table, &col_widths, row, &mut buf);
+        // print the body, one row at a time
+        for row in body_start..layout.num_rows() {
+            buf.clear();
+            print_row(&layout, row, &mut buf);
+            out.write_str(&buf)?;
         }
 
-        buf
+        // print the footer as a paragraph
+        if let Some(footer) = *table.footer() {
+            buf.clear();
+            buf.push_str(NEWLINE);
+            buf.push_str(&escape(footer.text()));
+            buf.push_str(NEWLINE);
+            out.write_str(&buf)?;
+        }
+
+        // print the notes referenced by the cells, each as a paragraph of its own
+        for footnote in layout.footnotes() {
+            buf.clear();
+            buf.push_str(NEWLINE);
+            buf.push_str(&footnote_marker(footnote.number));
+            buf.push(' ');
+            buf.push_str(&footnote.text);
+            buf.push_str(NEWLINE);
+            out.write_str(&buf)?;
+        }
+        Ok(())
     }
 }
 
-fn print_header_format(table: &Table, col_widths: &[usize], buf: &mut String) {
+impl Paginate for Markdown {
+    fn geometry(&self) -> Geometry {
+        Geometry {
+            merge_spans: false,
+            outer_width: 2,
+            header_line: Line::Bold,
+            row_separator_lines: 0,
+            header_separator_lines: 0,
+        }
+    }
+
+    fn footnote_lines(&self, footnote: &Footnote) -> usize {
+        // the note is preceded by a blank line
+        1 + usize::max(1, footnote.text.lines().count())
+    }
+
+    fn frame_lines(&self, table: &Table, _: &Layout) -> usize {
+        // the line between the header and the body, and each caption followed by a blank line; a
+        // blank header is reserved if the first row isn't a header row, as every page but the first
+        // then hides it
+        let blank_header = !Header::resolve_or_default(&table.row(0).blended_styles()).0;
+        1 + usize::from(blank_header)
+            + 2 * (usize::from(table.title().is_some()) + usize::from(table.footer().is_some()))
+    }
+}
+
+fn print_header_format(layout: &Layout, buf: &mut String) {
     buf.push('|');
-    for (col, &width) in col_widths.iter().enumerate() {
-        let col = table.col(col);
-        let styles = col.blended_styles();
-        let alignment = HAlign::resolve_or_default(&styles);
+    for (col, &width) in layout.col_widths().iter().enumerate() {
+        let alignment = HAlign::resolve_or_default(layout.col_styles(col));
         let alignment = alignment.borrow();
         match alignment {
             HAlign::Left | HAlign::Right => {
@@ -58,38 +139,42 @@ fn print_header_format(table: &Table, col_widths: &[usize], buf: &mut String) {
     buf.push_str(NEWLINE);
 }
 
-fn print_row(renderer: &Markdown, table: &Table, col_widths: &[usize], row: usize, buf: &mut String) {
-    // first pass: wrap individual cell data over multiple rows
-    let cell_lines = (0..col_widths.len())
-        .into_iter()
-        .map(|col| {
-            let cell = table.cell(col, row);
-            let data = cell.as_ref().map_or(Cow::Borrowed(""), |cell| cell.data().render(renderer));
-            wrap(&data, col_widths[col])
-        })
-        .collect::<Vec<_>>();
-
-    // second pass: obtain the combined styles of each cell
-    let cell_styles = (0..col_widths.len())
-        .into_iter()
-        .map(|col| {
-            let cell = table.cell(col, row);
-            cell.blended_styles()
-        })
-        .collect::<Vec<_>>();
-
-    // third pass: render each line in the row
-    let max_lines = cell_lines.iter().map(Vec::len).max().unwrap();
-    for line in 0..max_lines {
+fn print_row(layout: &Layout, row: usize, buf: &mut String) {
+    // Markdown has no notion of merged cells, so a spanning cell occupies only its own column,
+    // leaving the rest of the span blank
+    for line in 0..layout.row_height(row) {
         buf.push('|');
-        for col in 0..col_widths.len() {
-            let line = cell_lines[col].get(line).map_or("", |line| &line[..]);
-            let styles = &cell_styles[col];
-            let alignment = HAlign::resolve_or_default(styles);
-            let line = pad(line, ' ', col_widths[col], &alignment);
+        for col in 0..layout.num_cols() {
+            let cell = layout.cell(col, row);
+            let alignment = HAlign::resolve_or_default(&cell.styles);
+            let line = pad(cell.line(line), ' ', layout.col_widths()[col], &alignment);
             buf.push_str(&line);
             buf.push('|');
         }
         buf.push_str(NEWLINE);
     }
-}
\ No newline at end of file
+}
+
+/// Escapes the characters of the caption that would otherwise be read as Markdown syntax.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '~' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+fn print_blank_row(layout: &Layout, buf: &mut String) {
+    buf.push('|');
+    for &width in layout.col_widths() {
+        (0..width).for_each(|_| buf.push(' '));
+        buf.push('|');
+    }
+    buf.push_str(NEWLINE);
+}
+
+#[cfg(test)]
+mod tests;
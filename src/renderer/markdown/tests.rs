@@ -0,0 +1,56 @@
+use crate::renderer::markdown::Markdown;
+use crate::renderer::{RenderHint, Renderer, Viewport};
+use crate::style::{Header, Styles};
+use crate::table::{Row, Table};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[test]
+fn render_header() {
+    #[derive(Debug)]
+    struct Case {
+        input: (bool, Option<Viewport>),
+        expect: &'static str,
+    }
+
+    for case in [
+        Case {
+            input: (true, None),
+            expect: "|h|\n|:-|\n|a|\n|b|\n|c|\n",
+        },
+        Case {
+            input: (true, Some(Viewport::rows(2..3))),
+            expect: "|h|\n|:-|\n|b|\n",
+        },
+        // the first row serves as the header, unless it is hidden by the viewport
+        Case {
+            input: (false, None),
+            expect: "|a|\n|:-|\n|b|\n|c|\n",
+        },
+        Case {
+            input: (false, Some(Viewport::rows(2..3))),
+            expect: "| |\n|:-|\n|c|\n",
+        },
+    ] {
+        let mut table = Table::default();
+        if case.input.0 {
+            table.push_row(Row::new(Styles::default().with(Header(true)), vec!["h".into()]));
+        }
+        let table = table.with_row(["a"]).with_row(["b"]).with_row(["c"]);
+        let hints = case.input.1.clone().into_iter().map(RenderHint::Viewport).collect::<Vec<_>>();
+        let rendered = Markdown::default().render_with_hints(&table, &hints);
+        assert_eq!(case.expect, rendered, "for {:?}", &case);
+    }
+}
+
+#[test]
+fn render_escaped_captions() {
+    let table = Table::default()
+        .with_title("*Q1* | #1")
+        .with_footer("<draft>")
+        .with_row(["a"]);
+    assert_eq!(
+        "### \\*Q1\\* \\| \\#1\n\n|a|\n|:-|\n\n\\<draft\\>\n",
+        Markdown::default().render(&table)
+    );
+}
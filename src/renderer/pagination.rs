@@ -0,0 +1,171 @@
+//! Splitting a long table into pages of a fixed number of lines.
+//!
+//! Every page repeats the [`Header`](crate::style::Header) rows of the table and lays out the
+//! columns at the same widths, which are measured once over the entire table. Rows that wrap onto
+//! several lines are accounted for by their laid out height, as are the footnotes that they
+//! reference, and a row is never split across pages.
+
+use crate::renderer::layout::{Geometry, Layout};
+use crate::renderer::{RenderHint, StreamRenderer, Viewport, NEWLINE};
+use crate::table::{Footnote, Table};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A renderer that draws every row of a table on a whole number of lines, so that the table may
+/// be split into pages.
+pub trait Paginate: StreamRenderer {
+    /// The geometry with which the renderer lays out a table.
+    fn geometry(&self) -> Geometry;
+
+    /// The number of lines taken up by the given footnote, printed below the table.
+    fn footnote_lines(&self, footnote: &Footnote) -> usize;
+
+    /// The number of lines drawn for the given table, laid out as given, in addition to its rows
+    /// and the lines separating them, such as the outer borders and captions.
+    fn frame_lines(&self, table: &Table, layout: &Layout) -> usize;
+}
+
+/// Splits a table into pages of at most `page_lines` lines. If a `continued` footer is given, it
+/// is appended to every page but the last, and counts towards its lines.
+///
+/// A page always contains at least one row besides the headers; a row that cannot fit on a page
+/// of its own overflows it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pagination {
+    pub page_lines: usize,
+    pub continued: Option<String>,
+}
+
+impl Pagination {
+    pub fn new(page_lines: usize) -> Self {
+        Self {
+            page_lines,
+            continued: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_continued(mut self, continued: impl Into<String>) -> Self {
+        self.continued = Some(continued.into());
+        self
+    }
+
+    /// Renders the given table one page at a time.
+    ///
+    /// # Panics
+    /// If the table is empty.
+    pub fn pages<'a, R: Paginate>(
+        &self,
+        renderer: &'a R,
+        table: &'a Table,
+    ) -> impl Iterator<Item = String> + 'a {
+        assert!(!table.is_empty(), "table cannot be empty");
+        let layout = Layout::new(table, renderer, &[], &renderer.geometry());
+        let ranges = self.page_ranges(&layout, renderer.frame_lines(table, &layout), renderer);
+        let num_pages = ranges.len();
+        let col_widths = layout.col_widths().to_vec();
+        let continued = self.continued.clone();
+
+        ranges.into_iter().enumerate().map(move |(page, rows)| {
+            let hints = [
+                RenderHint::Viewport(Viewport::rows(rows)),
+                RenderHint::ColWidths(col_widths.clone()),
+            ];
+            let mut buf = String::new();
+            renderer.render_into(table, &hints, &mut buf).unwrap();
+            if let Some(continued) = continued.as_ref().filter(|_| page < num_pages - 1) {
+                if !buf.ends_with(NEWLINE) {
+                    buf.push_str(NEWLINE);
+                }
+                buf.push_str(continued);
+                buf.push_str(NEWLINE);
+            }
+            buf
+        })
+    }
+
+    /// Groups the rows of a laid out table into pages, each given by the range of rows that it
+    /// spans. Header rows are drawn on every page, irrespective of the range.
+    fn page_ranges(
+        &self,
+        layout: &Layout,
+        frame_lines: usize,
+        renderer: &impl Paginate,
+    ) -> Vec<Range<usize>> {
+        let geometry = renderer.geometry();
+        let continued_lines = self.continued.as_ref().map_or(0, |continued| {
+            usize::max(1, continued.lines().count())
+        });
+        let header_rows = (0..layout.num_rows())
+            .filter(|&row| layout.is_header_row(row))
+            .collect::<Vec<_>>();
+        let body_rows = (0..layout.num_rows())
+            .filter(|&row| !layout.is_header_row(row))
+            .collect::<Vec<_>>();
+
+        // the lines taken up by each row, including the notes referenced by its cells
+        let mut row_lines = (0..layout.num_rows())
+            .map(|row| layout.row_height(row))
+            .collect::<Vec<_>>();
+        for footnote in layout.footnotes() {
+            row_lines[footnote.row] += renderer.footnote_lines(footnote);
+        }
+
+        // the lines taken up by a page with the given number of rows, besides their content; the
+        // headers are separated from the first body row by the header separator
+        let overhead = |rows: usize| {
+            let separators = rows.saturating_sub(1);
+            if header_rows.is_empty() || rows <= header_rows.len() {
+                frame_lines + separators * geometry.row_separator_lines
+            } else {
+                frame_lines
+                    + (separators - 1) * geometry.row_separator_lines
+                    + geometry.header_separator_lines
+            }
+        };
+        let header_lines = header_rows.iter().map(|&row| row_lines[row]).sum::<usize>();
+        let budget = self.page_lines.saturating_sub(continued_lines);
+
+        let mut ranges = Vec::new();
+        let mut remaining_lines = body_rows.iter().map(|&row| row_lines[row]).sum::<usize>();
+        let mut start = 0;
+        while start < body_rows.len() {
+            // the remaining rows form the last page if they fit without the continued footer
+            let remaining_rows = header_rows.len() + body_rows.len() - start;
+            if header_lines + remaining_lines + overhead(remaining_rows) <= self.page_lines {
+                ranges.push(body_rows[start]..layout.num_rows());
+                break;
+            }
+
+            // otherwise, fill the page greedily, taking at least one row
+            let mut end = start + 1;
+            let mut lines = row_lines[body_rows[start]];
+            while end < body_rows.len() {
+                let next_lines = lines + row_lines[body_rows[end]];
+                let rows = header_rows.len() + end + 1 - start;
+                if header_lines + next_lines + overhead(rows) > budget {
+                    break;
+                }
+                lines = next_lines;
+                end += 1;
+            }
+            let next = body_rows.get(end).copied().unwrap_or(layout.num_rows());
+            ranges.push(body_rows[start]..next);
+            remaining_lines -= lines;
+            start = end;
+        }
+
+        match ranges.first_mut() {
+            // the first page also takes in any header rows preceding the first body row
+            Some(first) => first.start = 0,
+
+            // a table comprising only header rows is drawn on a single page
+            None => ranges.push(0..layout.num_rows()),
+        }
+        ranges
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,90 @@
+use crate::renderer::console::{Console, Decor};
+use crate::renderer::markdown::Markdown;
+use crate::renderer::pagination::Pagination;
+use crate::style::{Header, MaxWidth, Styles};
+use crate::table::{Col, Row, Table};
+use alloc::vec;
+use alloc::vec::Vec;
+
+fn table() -> Table {
+    Table::default()
+        .with_cols(vec![Col::default(), Col::new(Styles::default().with(MaxWidth(6)))])
+        .with_row(Row::new(Styles::default().with(Header(true)), vec!["#".into(), "Name".into()]))
+        .with_row(["1", "Ada"])
+        .with_row(["2", "Grace Hopper"])
+        .with_row(["3", "Alan"])
+        .with_row(["4", "Edsger"])
+}
+
+#[test]
+fn console_pages() {
+    let table = table();
+    let console = Console(Decor::default().suppress_escape_codes());
+    let pages = Pagination::new(7)
+        .with_continued("(continued)")
+        .pages(&console, &table)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            "╔═╤══════╗\n║#│Name  ║\n╠═╪══════╣\n║1│Ada   ║\n╚═╧══════╝\n(continued)\n",
+            "╔═╤══════╗\n║#│Name  ║\n╠═╪══════╣\n║2│Grace ║\n║ │Hopper║\n╚═╧══════╝\n(continued)\n",
+            "╔═╤══════╗\n║#│Name  ║\n╠═╪══════╣\n║3│Alan  ║\n╟─┼──────╢\n║4│Edsger║\n╚═╧══════╝",
+        ],
+        pages
+    );
+}
+
+#[test]
+fn markdown_pages() {
+    let table = table();
+    for (page_lines, expect) in [
+        (
+            4,
+            vec![
+                "|#|Name  |\n|:-|:-----|\n|1|Ada   |\n",
+                "|#|Name  |\n|:-|:-----|\n|2|Grace |\n| |Hopper|\n",
+                "|#|Name  |\n|:-|:-----|\n|3|Alan  |\n|4|Edsger|\n",
+            ],
+        ),
+        // a row taller than the page overflows it
+        (
+            3,
+            vec![
+                "|#|Name  |\n|:-|:-----|\n|1|Ada   |\n",
+                "|#|Name  |\n|:-|:-----|\n|2|Grace |\n| |Hopper|\n",
+                "|#|Name  |\n|:-|:-----|\n|3|Alan  |\n",
+                "|#|Name  |\n|:-|:-----|\n|4|Edsger|\n",
+            ],
+        ),
+        (
+            100,
+            vec!["|#|Name  |\n|:-|:-----|\n|1|Ada   |\n|2|Grace |\n| |Hopper|\n|3|Alan  |\n|4|Edsger|\n"],
+        ),
+    ] {
+        let actual = Pagination::new(page_lines)
+            .pages(&Markdown::default(), &table)
+            .collect::<Vec<_>>();
+        assert_eq!(expect, actual, "for {page_lines}");
+    }
+}
+
+#[test]
+fn last_row_overflows_page() {
+    // every row, the last included, is too tall to fit on a page of its own
+    let table = Table::default().with_row(["a"]).with_row(["b"]);
+    let console = Console(Decor::default().suppress_escape_codes());
+    let pages = Pagination::new(2).pages(&console, &table).collect::<Vec<_>>();
+    assert_eq!(vec!["╔═╗\n║a║\n╚═╝", "╔═╗\n║b║\n╚═╝"], pages);
+
+    let table = table.with_title("Title").with_footer("Footer");
+    let pages = Pagination::new(6)
+        .pages(&Markdown::default(), &table)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            "### Title\n\n|a|\n|:-|\n\nFooter\n",
+            "### Title\n\n| |\n|:-|\n|b|\n\nFooter\n",
+        ],
+        pages
+    );
+}
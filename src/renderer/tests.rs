@@ -1,5 +1,18 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
+use crate::renderer::console::{Console, Decor};
+use crate::renderer::html::Html;
+use crate::renderer::markdown::Markdown;
+use crate::renderer::{Fit, Renderer};
+use crate::style::{
+    ColourDepth, Conditional, HAlign, Header, Overflow, PaddingBottom, PaddingLeft, PaddingRight,
+    PaddingTop, Palette16, Styles, TextFg, VAlign,
+};
+use crate::table::{Col, Content, Row, Table};
+use core::cell::Cell;
 
 #[test]
 fn wrap() {
@@ -104,6 +117,66 @@ fn wrap() {
     }
 }
 
+#[cfg(feature = "unicode")]
+#[test]
+fn wrap_glyphs_wider_than_line() {
+    #[derive(Debug)]
+    struct Case {
+        input: (&'static str, usize),
+        expect: Vec<&'static str>,
+    }
+
+    for case in [
+        Case {
+            input: ("日本語", 3),
+            expect: vec!["日", "本", "語"],
+        },
+        // a glyph wider than the line is replaced, rather than overflowing the line
+        Case {
+            input: ("日本", 1),
+            expect: vec!["…", "…"],
+        },
+        Case {
+            input: ("a日b", 1),
+            expect: vec!["a", "…", "b"],
+        },
+    ] {
+        let actual = super::wrap(case.input.0, case.input.1);
+        assert_eq!(case.expect, actual, "for {:?}", &case);
+    }
+}
+
+#[test]
+fn wrap_reopens_sgr() {
+    #[derive(Debug)]
+    struct Case {
+        input: (&'static str, usize),
+        expect: Vec<&'static str>,
+    }
+
+    for case in [
+        Case {
+            input: ("\x1b[31mred\x1b[0m text", 10),
+            expect: vec!["\x1b[31mred\x1b[0m text"],
+        },
+        Case {
+            input: ("\x1b[31mthe quick\x1b[0m brown", 5),
+            expect: vec!["\x1b[31mthe\x1b[0m", "\x1b[31mquick\x1b[0m", "brown"],
+        },
+        Case {
+            input: ("\x1b[1m\x1b[4mabcdefg\x1b[m", 4),
+            expect: vec!["\x1b[1m\x1b[4mabcd\x1b[0m", "\x1b[1m\x1b[4mefg\x1b[m"],
+        },
+        Case {
+            input: ("\x1b[32mabc\ndef\x1b[0m", 10),
+            expect: vec!["\x1b[32mabc\x1b[0m", "\x1b[32mdef\x1b[0m"],
+        },
+    ] {
+        let actual = super::wrap(case.input.0, case.input.1);
+        assert_eq!(case.expect, actual, "for {:?}", &case);
+    }
+}
+
 #[test]
 fn split_preserving_whitespace() {
     #[derive(Debug)]
@@ -174,3 +247,220 @@ fn split_preserving_whitespace() {
 fn count_whitespace(s: &str) -> usize {
     s.chars().filter(|ch| ch.is_whitespace()).count()
 }
+
+#[test]
+fn fit_widths() {
+    let overhead = |cols: usize| cols + 1;
+
+    // already fits
+    assert_eq!(vec![10, 20], super::fit_widths(vec![10, 20], &[1, 1], 33, Fit::Proportional, overhead));
+
+    // proportional, with the rounding remainder handed out from the left
+    assert_eq!(vec![7, 13], super::fit_widths(vec![10, 20], &[1, 1], 23, Fit::Proportional, overhead));
+
+    // proportional, with a column pinned to its floor
+    assert_eq!(vec![8, 12], super::fit_widths(vec![10, 20], &[8, 1], 23, Fit::Proportional, overhead));
+
+    // cannot shrink below the floors
+    assert_eq!(vec![5, 5], super::fit_widths(vec![10, 20], &[5, 5], 3, Fit::Proportional, overhead));
+
+    // widest first
+    assert_eq!(vec![10, 10], super::fit_widths(vec![10, 20], &[1, 1], 23, Fit::ShrinkWidestFirst, overhead));
+    assert_eq!(vec![8, 9], super::fit_widths(vec![10, 20], &[1, 1], 20, Fit::ShrinkWidestFirst, overhead));
+
+    // dropping columns, always leaving at least one
+    assert_eq!(vec![10], super::fit_widths(vec![10, 20], &[1, 1], 23, Fit::DropRightmostCols, overhead));
+    assert_eq!(vec![10], super::fit_widths(vec![10, 20], &[1, 1], 5, Fit::DropRightmostCols, overhead));
+}
+
+#[test]
+fn col_bands() {
+    let overhead = |cols: usize| cols + 1;
+    let table = Table::default()
+        .with_cols(vec![
+            Col::default(),
+            Col::new(Styles::default().with(Header(true))),
+            Col::default(),
+            Col::default(),
+        ])
+        .with_row(["a", "b", "c", "d"]);
+    let widths = [10, 4, 10, 10];
+
+    // everything fits in a single band
+    assert_eq!(vec![0..4], table.col_bands(&widths, 100, overhead));
+
+    // the header column is repeated in every band; the first band takes in the columns before it
+    assert_eq!(vec![0..3, 3..4], table.col_bands(&widths, 30, overhead));
+    assert_eq!(vec![0..2, 2..3, 3..4], table.col_bands(&widths, 20, overhead));
+
+    // a column too wide for a band of its own overflows it
+    assert_eq!(vec![0..2, 2..3, 3..4], table.col_bands(&widths, 5, overhead));
+}
+
+#[test]
+fn truncate() {
+    #[derive(Debug)]
+    struct Case {
+        input: (&'static str, usize, Overflow),
+        expect: &'static str,
+    }
+
+    for case in [
+        Case {
+            input: ("abcdef", 6, Overflow::Truncate),
+            expect: "abcdef",
+        },
+        Case {
+            input: ("abcdef", 4, Overflow::Truncate),
+            expect: "abcd",
+        },
+        Case {
+            input: ("abcdef", 4, Overflow::TruncateWithEllipsis),
+            expect: "abc…",
+        },
+        Case {
+            input: ("abcdef", 0, Overflow::TruncateWithEllipsis),
+            expect: "",
+        },
+        Case {
+            input: ("abcdefgh", 6, Overflow::TruncateMiddle),
+            expect: "abc…gh",
+        },
+        Case {
+            input: ("abcdefgh", 5, Overflow::TruncateMiddle),
+            expect: "ab…gh",
+        },
+        Case {
+            input: ("abcdefgh", 1, Overflow::TruncateMiddle),
+            expect: "…",
+        },
+        Case {
+            input: ("\x1b[31mabcdef\x1b[0m", 4, Overflow::TruncateWithEllipsis),
+            expect: "\x1b[31mabc…\x1b[0m",
+        },
+    ] {
+        let actual = super::truncate(case.input.0, case.input.1, &case.input.2);
+        assert_eq!(case.expect, actual, "for {:?}", &case);
+    }
+}
+
+#[test]
+fn footnote_marker() {
+    assert_eq!("¹", super::footnote_marker(1));
+    assert_eq!("¹⁰", super::footnote_marker(10));
+    assert_eq!("²⁴⁶", super::footnote_marker(246));
+}
+
+#[test]
+fn vertical_offset() {
+    assert_eq!(0, super::vertical_offset(2, 5, &VAlign::Top));
+    assert_eq!(1, super::vertical_offset(2, 5, &VAlign::Middle));
+    assert_eq!(3, super::vertical_offset(2, 5, &VAlign::Bottom));
+    assert_eq!(0, super::vertical_offset(5, 2, &VAlign::Bottom));
+}
+
+#[test]
+fn pad_cell() {
+    let styles = Styles::default()
+        .with(PaddingLeft(2))
+        .with(PaddingRight(1))
+        .with(PaddingTop(1))
+        .with(PaddingBottom(1))
+        .with(HAlign::Right);
+    assert_eq!(4, super::content_width(7, &styles));
+    assert_eq!(
+        vec!["", "    abc ", "     de ", ""],
+        super::pad_cell(vec!["abc".to_string(), "de".to_string()], 8, &styles)
+    );
+    assert_eq!(
+        vec!["abc"],
+        super::pad_cell(vec!["abc".to_string()], 8, &Styles::default())
+    );
+}
+
+#[test]
+fn render_computed_once() {
+    let invocations = Rc::new(Cell::new(0));
+    let table = Table::default().with_row(Row::new(
+        Styles::default(),
+        vec![
+            "count".into(),
+            Content::Computed(Box::new({
+                let invocations = invocations.clone();
+                move || {
+                    invocations.set(invocations.get() + 1);
+                    invocations.get().to_string()
+                }
+            }))
+            .into(),
+        ],
+    ));
+
+    Console::default().render(&table);
+    assert_eq!(1, invocations.get());
+
+    Markdown::default().render(&table);
+    assert_eq!(2, invocations.get());
+}
+
+#[test]
+fn render_nested_computed_once() {
+    let invocations = Rc::new(Cell::new(0));
+    let computed = Content::Computed(Box::new({
+        let invocations = invocations.clone();
+        move || {
+            invocations.set(invocations.get() + 1);
+            "x".into()
+        }
+    }));
+
+    // the computed content sits two levels deep, in a table nested in a nested table
+    let inner = Table::default().with_row(Row::new(Styles::default(), vec![computed.into()]));
+    let outer = Table::default().with_row(Row::new(Styles::default(), vec![inner.into()]));
+    let table = Table::default().with_row(Row::new(Styles::default(), vec![outer.into()]));
+
+    Console::default().render(&table);
+    assert_eq!(1, invocations.get());
+
+    // merging the borders reuses the layouts measured for the nested tables
+    Console(Decor::default().merge_nested_borders()).render(&table);
+    assert_eq!(2, invocations.get());
+}
+
+#[test]
+fn render_conditional_computed_once() {
+    // the computed content counts its invocations, and is coloured whenever the count is odd
+    let invocations = Rc::new(Cell::new(0));
+    let odd = Conditional::default().with_rule(
+        |subject| matches!(subject.text.parse::<usize>(), Ok(count) if count % 2 == 1),
+        Styles::default().with(TextFg(Palette16::Red.into())),
+    );
+    let table = Table::with_styles(Styles::default().with(odd)).with_row(Row::new(
+        Styles::default(),
+        vec![Content::Computed(Box::new({
+            let invocations = invocations.clone();
+            move || {
+                invocations.set(invocations.get() + 1);
+                invocations.get().to_string()
+            }
+        }))
+        .into()],
+    ));
+
+    // the condition is evaluated against the rendered text
+    let console = Console(Decor::default().with_colour_depth(ColourDepth::Palette16));
+    let rendered = console.render(&table);
+    assert_eq!(1, invocations.get());
+    assert!(rendered.contains("\x1b[31m1\x1b[0m"), "in {:?}", rendered);
+
+    let rendered = Html::default().render(&table);
+    assert_eq!(2, invocations.get());
+    assert!(!rendered.contains("color"), "in {:?}", rendered);
+
+    let rendered = Html::default().render(&table);
+    assert_eq!(3, invocations.get());
+    assert!(rendered.contains(">3</span>") && rendered.contains("color"), "in {:?}", rendered);
+
+    Markdown::default().render(&table);
+    assert_eq!(4, invocations.get());
+}
@@ -0,0 +1,104 @@
+//! Measurement of text as it appears on a terminal.
+//!
+//! By default, every `char` is assumed to be a single grapheme, one column wide. With the
+//! `unicode` feature enabled, text is segmented into extended grapheme clusters and measured
+//! according to the East Asian Width rules, so that combining marks occupy no columns and
+//! wide glyphs (CJK ideographs, most emoji) occupy two.
+//!
+//! In either case, ANSI escape sequences (CSI and OSC) embedded in the text are treated as
+//! indivisible graphemes that occupy no columns, so that pre-styled text may be measured and
+//! wrapped safely.
+
+use core::iter;
+
+const ESC: char = '\x1b';
+
+/// The number of columns occupied by the given string.
+pub fn display_width(s: &str) -> usize {
+    graphemes(s).map(grapheme_width).sum()
+}
+
+/// Splits the given string into graphemes, which are the smallest units of text that may be
+/// separated by a line break. An escape sequence is yielded as a single grapheme.
+pub fn graphemes(s: &str) -> impl Iterator<Item = &str> {
+    let mut remaining = s;
+    iter::from_fn(move || {
+        if remaining.is_empty() {
+            None
+        } else {
+            let (grapheme, rest) = remaining.split_at(next_grapheme_len(remaining));
+            remaining = rest;
+            Some(grapheme)
+        }
+    })
+}
+
+/// Whether the given grapheme is an escape sequence.
+pub fn is_escape(grapheme: &str) -> bool {
+    grapheme.starts_with(ESC)
+}
+
+/// The number of columns occupied by a single grapheme.
+#[cfg(not(feature = "unicode"))]
+pub fn grapheme_width(grapheme: &str) -> usize {
+    usize::from(!is_escape(grapheme))
+}
+
+/// The number of columns occupied by a single grapheme.
+#[cfg(feature = "unicode")]
+pub fn grapheme_width(grapheme: &str) -> usize {
+    if is_escape(grapheme) {
+        0
+    } else {
+        unicode_width::UnicodeWidthStr::width(grapheme)
+    }
+}
+
+/// The length, in bytes, of the first grapheme in a nonempty string.
+fn next_grapheme_len(s: &str) -> usize {
+    if s.starts_with(ESC) {
+        escape_len(s)
+    } else {
+        #[cfg(not(feature = "unicode"))]
+        let len = s.chars().next().map_or(0, char::len_utf8);
+
+        // a grapheme cluster never extends past a control character, so the escape sequences
+        // that follow are left intact
+        #[cfg(feature = "unicode")]
+        let len = unicode_segmentation::UnicodeSegmentation::graphemes(s, true)
+            .next()
+            .map_or(0, str::len);
+
+        len
+    }
+}
+
+/// The length, in bytes, of the escape sequence at the start of the given string. An
+/// unterminated sequence extends to the end of the string.
+fn escape_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    match bytes.get(1) {
+        Some(b'[') => {
+            // CSI: parameter and intermediate bytes, followed by a single final byte
+            bytes[2..]
+                .iter()
+                .position(|b| (0x40..=0x7e).contains(b))
+                .map_or(s.len(), |i| i + 3)
+        }
+        Some(b']') => {
+            // OSC: terminated by either BEL or ST
+            (2..bytes.len())
+                .find_map(|i| match bytes[i] {
+                    0x07 => Some(i + 1),
+                    0x1b if bytes.get(i + 1) == Some(&b'\\') => Some(i + 2),
+                    _ => None,
+                })
+                .unwrap_or(s.len())
+        }
+        Some(_) => 1 + s[1..].chars().next().map_or(0, char::len_utf8),
+        None => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests;
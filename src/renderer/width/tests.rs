@@ -0,0 +1,40 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[test]
+fn display_width() {
+    assert_eq!(0, super::display_width(""));
+    assert_eq!(5, super::display_width("hello"));
+}
+
+#[test]
+fn display_width_skips_escapes() {
+    assert_eq!(5, super::display_width("\x1b[31;1mhello\x1b[0m"));
+    assert_eq!(4, super::display_width("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07"));
+    assert_eq!(0, super::display_width("\x1b[31"));
+}
+
+#[test]
+fn graphemes() {
+    assert_eq!(vec!["a", "b", "c"], super::graphemes("abc").collect::<Vec<_>>());
+    assert_eq!(
+        vec!["a", "\x1b[1m", "b", "\x1b]0;title\x07", "c", "\x1bM"],
+        super::graphemes("a\x1b[1mb\x1b]0;title\x07c\x1bM").collect::<Vec<_>>()
+    );
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn display_width_unicode() {
+    assert_eq!(4, super::display_width("日本"));
+    assert_eq!(4, super::display_width("Zoe\u{0301}\u{0308}y"));
+    assert_eq!(vec!["e\u{0301}", "x"], super::graphemes("e\u{0301}x").collect::<Vec<_>>());
+}
+
+#[cfg(feature = "unicode")]
+#[test]
+fn wrap_wide_glyphs() {
+    assert_eq!(vec!["日本", "語の", "文"], crate::renderer::wrap("日本語の文", 5));
+    assert_eq!(vec!["…", "…"], crate::renderer::wrap("日本", 1));
+    assert_eq!("日本 ", crate::renderer::pad("日本", ' ', 5, &crate::style::HAlign::Left));
+}
@@ -1,31 +1,55 @@
+pub mod banding;
 pub mod blink;
 pub mod bold;
 pub mod border_bg;
+pub mod border_bottom;
 pub mod border_fg;
+pub mod border_left;
+pub mod border_right;
+pub mod border_top;
+pub mod col_span;
+pub mod colour;
+pub mod conditional;
 pub mod fill_bg;
 pub mod fill_invert;
 pub mod halign;
 pub mod header;
 pub mod italic;
+pub mod line;
 pub mod max_width;
 pub mod min_width;
+pub mod overflow;
+pub mod padding_bottom;
+pub mod padding_left;
+pub mod padding_right;
+pub mod padding_top;
 pub mod palette_16;
+pub mod row_span;
 pub mod separator;
 pub mod strikethrough;
 pub mod text_bg;
 pub mod text_fg;
 pub mod text_invert;
 pub mod underline;
+pub mod valign;
 
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::collections::btree_map::Iter;
 use alloc::collections::BTreeMap;
 use alloc::string::String;
+pub use banding::Banding;
 pub use blink::Blink;
 pub use bold::Bold;
 pub use border_bg::BorderBg;
+pub use border_bottom::BorderBottom;
 pub use border_fg::BorderFg;
+pub use border_left::BorderLeft;
+pub use border_right::BorderRight;
+pub use border_top::BorderTop;
+pub use col_span::ColSpan;
+pub use colour::{Colour, ColourDepth};
+pub use conditional::{Conditional, Rule, Subject};
 use core::any;
 use core::any::Any;
 pub use fill_bg::FillBg;
@@ -33,15 +57,23 @@ pub use fill_invert::FillInvert;
 pub use halign::HAlign;
 pub use header::Header;
 pub use italic::Italic;
+pub use line::Line;
 pub use max_width::MaxWidth;
 pub use min_width::MinWidth;
+pub use overflow::Overflow;
+pub use padding_bottom::PaddingBottom;
+pub use padding_left::PaddingLeft;
+pub use padding_right::PaddingRight;
+pub use padding_top::PaddingTop;
 pub use palette_16::Palette16;
+pub use row_span::RowSpan;
 pub use separator::Separator;
 pub use strikethrough::Strikethrough;
 pub use text_bg::TextBg;
 pub use text_fg::TextFg;
 pub use text_invert::TextInvert;
 pub use underline::Underline;
+pub use valign::VAlign;
 
 mod private {
     use alloc::boxed::Box;
@@ -96,7 +128,7 @@ pub trait Style: Any + private::Replica + private::Upcast {
         }
     }
 
-    fn resolve_or_default(styles: &Styles) -> Cow<Self>
+    fn resolve_or_default(styles: &Styles) -> Cow<'_, Self>
     where
         Self: Default + Sized + Clone,
     {
@@ -186,6 +218,9 @@ pub trait Styled {
 
 /// Indicates the element types to which a particular [`Style`] may be assigned.
 pub enum Assignability {
+    /// At the cell level only.
+    CellOnly,
+
     /// At the table level only.
     TableOnly,
 
@@ -218,7 +253,7 @@ impl Assignability {
     }
 
     pub fn at_cell(&self) -> bool {
-        matches!(self, Assignability::CellRowColTable)
+        matches!(self, Assignability::CellOnly | Assignability::CellRowColTable)
     }
 }
 
@@ -0,0 +1,53 @@
+use crate::style::{Assignability, Style, Styles};
+
+/// Shades alternating bands of body rows, so that long rows are easier to follow. Rows are
+/// counted from the first that is neither a [`Header`](super::Header) nor a
+/// [`Separator`](super::Separator); those rows are never shaded and don't count towards the
+/// bands.
+///
+/// Each band comprises `rows` consecutive body rows, and the bands cycle with the given `period`,
+/// the last band of every cycle being shaded with the given styles. By default, every other row
+/// is shaded. The styles of a shaded band take precedence over those of the table and the columns,
+/// but not over those of the rows or the cells.
+#[derive(Clone)]
+pub struct Banding {
+    pub styles: Styles,
+    pub period: usize,
+    pub rows: usize,
+}
+
+impl Banding {
+    pub fn new(styles: Styles) -> Self {
+        styles.assert_assignability::<Self>(|assignability| assignability.at_row());
+        Self {
+            styles,
+            period: 2,
+            rows: 1,
+        }
+    }
+
+    #[must_use]
+    pub fn with_period(mut self, period: usize) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Bands the body rows in groups of the given number of rows.
+    #[must_use]
+    pub fn with_rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Whether the body row at the given index, counting only the body rows, is shaded.
+    pub fn is_shaded(&self, body_row: usize) -> bool {
+        let period = usize::max(1, self.period);
+        body_row / usize::max(1, self.rows) % period == period - 1
+    }
+}
+
+impl Style for Banding {
+    fn assignability(&self) -> Assignability {
+        Assignability::TableOnly
+    }
+}
@@ -1,10 +1,11 @@
-use crate::style::{Assignability, Palette16, Style};
+use crate::style::{Assignability, Colour, Style};
 
+/// The colour behind the border lines, which applies to the table as a whole.
 #[derive(Debug, Clone)]
-pub struct BorderBg(pub Palette16);
+pub struct BorderBg(pub Colour);
 
 impl Style for BorderBg {
     fn assignability(&self) -> Assignability {
         Assignability::TableOnly
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,12 @@
+use crate::style::{Assignability, Line, Style};
+
+/// The line drawn along the bottom of a cell, between it and the cell below. See
+/// [`BorderTop`](super::BorderTop) for how the lines of adjacent cells are reconciled.
+#[derive(Debug, Clone)]
+pub struct BorderBottom(pub Line);
+
+impl Style for BorderBottom {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
@@ -1,10 +1,11 @@
-use crate::style::{Assignability, Palette16, Style};
+use crate::style::{Assignability, Colour, Style};
 
+/// The colour of the border lines, which applies to the table as a whole.
 #[derive(Debug, Clone)]
-pub struct BorderFg(pub Palette16);
+pub struct BorderFg(pub Colour);
 
 impl Style for BorderFg {
     fn assignability(&self) -> Assignability {
         Assignability::TableOnly
     }
-}
\ No newline at end of file
+}
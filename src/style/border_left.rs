@@ -0,0 +1,12 @@
+use crate::style::{Assignability, Line, Style};
+
+/// The line drawn along the left of a cell, between it and the cell to its left. See
+/// [`BorderTop`](super::BorderTop) for how the lines of adjacent cells are reconciled.
+#[derive(Debug, Clone)]
+pub struct BorderLeft(pub Line);
+
+impl Style for BorderLeft {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
@@ -0,0 +1,12 @@
+use crate::style::{Assignability, Line, Style};
+
+/// The line drawn along the right of a cell, between it and the cell to its right. See
+/// [`BorderTop`](super::BorderTop) for how the lines of adjacent cells are reconciled.
+#[derive(Debug, Clone)]
+pub struct BorderRight(pub Line);
+
+impl Style for BorderRight {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
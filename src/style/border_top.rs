@@ -0,0 +1,21 @@
+use crate::style::{Assignability, Line, Style};
+
+/// The line drawn along the top of a cell, between it and the cell above.
+///
+/// The border between two cells may be assigned a line by either of them: by the
+/// [`BorderBottom`](super::BorderBottom) of the upper cell or the `BorderTop` of the lower, and
+/// likewise by the [`BorderRight`](super::BorderRight) of the left cell or the
+/// [`BorderLeft`](super::BorderLeft) of the right. Where both assign a line, any line prevails
+/// over [`Line::None`]; otherwise, the lower (or right) cell prevails. Only the borders between
+/// cells are assigned lines; the outer border is left to the renderer, as are the borders running
+/// through cells that span several rows or columns.
+///
+/// A renderer may omit a border between rows altogether if it assigns no line to any column.
+#[derive(Debug, Clone)]
+pub struct BorderTop(pub Line);
+
+impl Style for BorderTop {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
@@ -0,0 +1,18 @@
+use crate::style::{Assignability, Style};
+
+/// The number of columns occupied by a cell, including the column in which the cell resides.
+/// The cells in the row that are covered by the span are hidden.
+#[derive(Debug, Clone)]
+pub struct ColSpan(pub usize);
+
+impl Default for ColSpan {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl Style for ColSpan {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellOnly
+    }
+}
@@ -0,0 +1,138 @@
+use crate::style::Palette16;
+
+/// A colour that may be drawn from the 16-colour palette, the 256-colour indexed palette, or
+/// specified directly as a 24-bit RGB triple.
+///
+/// In the 256-colour palette, indices 0–15 correspond to [`Palette16`], 16–231 form a
+/// 6×6×6 colour cube, and 232–255 form a greyscale ramp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Colour {
+    Palette16(Palette16),
+    Palette256(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl From<Palette16> for Colour {
+    fn from(colour: Palette16) -> Self {
+        Colour::Palette16(colour)
+    }
+}
+
+/// The range of colours that a renderer is able to reproduce. Colours beyond the configured depth
+/// are downsampled to their nearest equivalent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColourDepth {
+    /// No colours are reproduced, although other text attributes (such as [`Bold`](super::Bold))
+    /// still are. To suppress the attributes as well, see
+    /// [`Decor::suppress_escape_codes`](crate::renderer::console::Decor::suppress_escape_codes).
+    Monochrome,
+    Palette16,
+    Palette256,
+    #[default]
+    TrueColour,
+}
+
+/// The intensities of each of the six steps along an axis of the 256-colour cube.
+const CUBE_STEPS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+const PALETTE_16: [Palette16; 16] = [
+    Palette16::Black,
+    Palette16::Red,
+    Palette16::Green,
+    Palette16::Yellow,
+    Palette16::Blue,
+    Palette16::Magenta,
+    Palette16::Cyan,
+    Palette16::White,
+    Palette16::BrightBlack,
+    Palette16::BrightRed,
+    Palette16::BrightGreen,
+    Palette16::BrightYellow,
+    Palette16::BrightBlue,
+    Palette16::BrightMagenta,
+    Palette16::BrightCyan,
+    Palette16::BrightWhite,
+];
+
+impl Colour {
+    /// Obtains the nearest colour that may be reproduced at the given depth. Colours that are
+    /// already within the depth are returned unchanged. As there is no colour that may be
+    /// reproduced at [`ColourDepth::Monochrome`], that depth is treated as
+    /// [`ColourDepth::Palette16`].
+    #[must_use]
+    pub fn downsample(&self, depth: ColourDepth) -> Colour {
+        match (self, depth) {
+            (Colour::Palette256(index), ColourDepth::Monochrome | ColourDepth::Palette16) => {
+                Colour::Palette16(nearest_palette_16(palette_256_rgb(*index)))
+            }
+            (&Colour::Rgb(r, g, b), ColourDepth::Monochrome | ColourDepth::Palette16) => {
+                Colour::Palette16(nearest_palette_16((r, g, b)))
+            }
+            (&Colour::Rgb(r, g, b), ColourDepth::Palette256) => {
+                Colour::Palette256(nearest_palette_256((r, g, b)))
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// The RGB components of this colour, or `None` for [`Palette16::Default`] and
+    /// [`Palette16::Hidden`], which do not correspond to any particular colour.
+    pub fn rgb(&self) -> Option<(u8, u8, u8)> {
+        match self {
+            Colour::Palette16(colour) => colour.rgb(),
+            Colour::Palette256(index) => Some(palette_256_rgb(*index)),
+            &Colour::Rgb(r, g, b) => Some((r, g, b)),
+        }
+    }
+}
+
+fn palette_256_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => PALETTE_16[usize::from(index)].rgb().unwrap(),
+        16..=231 => {
+            let index = usize::from(index - 16);
+            (
+                CUBE_STEPS[index / 36],
+                CUBE_STEPS[index / 6 % 6],
+                CUBE_STEPS[index % 6],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+/// Finds the entry in the 256-colour palette that is nearest to the given RGB triple, considering
+/// both the colour cube and the greyscale ramp. The first 16 entries are excluded, as their
+/// appearance varies between terminals.
+fn nearest_palette_256(rgb: (u8, u8, u8)) -> u8 {
+    let nearest_step = |component: u8| {
+        (0..CUBE_STEPS.len())
+            .min_by_key(|&i| CUBE_STEPS[i].abs_diff(component))
+            .unwrap() as u8
+    };
+    let cube_index = 16 + 36 * nearest_step(rgb.0) + 6 * nearest_step(rgb.1) + nearest_step(rgb.2);
+
+    let mean = (u16::from(rgb.0) + u16::from(rgb.1) + u16::from(rgb.2)) / 3;
+    let grey_index = 232 + (mean.saturating_sub(3) / 10).min(23) as u8;
+
+    [cube_index, grey_index]
+        .into_iter()
+        .min_by_key(|&index| distance(rgb, palette_256_rgb(index)))
+        .unwrap()
+}
+
+fn nearest_palette_16(rgb: (u8, u8, u8)) -> Palette16 {
+    PALETTE_16
+        .into_iter()
+        .min_by_key(|colour| distance(rgb, colour.rgb().unwrap()))
+        .unwrap()
+}
+
+/// The squared Euclidean distance between two colours in RGB space.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let square = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+    square(a.0, b.0) + square(a.1, b.1) + square(a.2, b.2)
+}
@@ -0,0 +1,67 @@
+use crate::style::{Assignability, Style, Styles};
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+
+/// Styles applied to the cells that satisfy a condition, evaluated against the content and the
+/// position of each cell. Rather than styling each cell individually as the table is built, one
+/// might, for example, colour all negative numbers red.
+///
+/// The rules are evaluated when the styles of a cell are blended, so they apply equally to all
+/// renderers. Every rule whose condition holds contributes its styles, with later rules taking
+/// precedence over earlier ones. The rules assigned to the table, the column, the row and the cell
+/// are each evaluated in turn; the styles they contribute take precedence over those assigned at
+/// the same level, but not over those assigned at a more specific level.
+#[derive(Clone, Default)]
+pub struct Conditional(pub Vec<Rule>);
+
+impl Conditional {
+    #[must_use]
+    pub fn with_rule(
+        mut self,
+        condition: impl Fn(&Subject) -> bool + 'static,
+        styles: Styles,
+    ) -> Self {
+        self.0.push(Rule::new(condition, styles));
+        self
+    }
+
+    /// The styles of the rules whose conditions hold for the given subject, in order.
+    pub fn matching<'a>(&'a self, subject: &'a Subject) -> impl Iterator<Item = &'a Styles> + 'a {
+        self.0
+            .iter()
+            .filter(|rule| (rule.condition)(subject))
+            .map(|rule| &rule.styles)
+    }
+}
+
+impl Style for Conditional {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
+
+/// A condition paired with the styles that apply to the cells satisfying it.
+#[derive(Clone)]
+pub struct Rule {
+    pub condition: Rc<dyn Fn(&Subject) -> bool>,
+    pub styles: Styles,
+}
+
+impl Rule {
+    pub fn new(condition: impl Fn(&Subject) -> bool + 'static, styles: Styles) -> Self {
+        styles.assert_assignability::<Self>(|assignability| assignability.at_cell());
+        Self {
+            condition: Rc::new(condition),
+            styles,
+        }
+    }
+}
+
+/// The cell against which a condition is evaluated. The text comprises the content of the cell,
+/// less any nested tables, and is empty for a missing cell.
+#[derive(Debug)]
+pub struct Subject<'a> {
+    pub col: usize,
+    pub row: usize,
+    pub text: &'a str,
+}
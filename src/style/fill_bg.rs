@@ -1,10 +1,12 @@
-use crate::style::{Assignability, Palette16, Style};
+use crate::style::{Assignability, Colour, Style};
 
+/// The colour filling the entire area of a cell, including its padding and any space not taken up
+/// by its text.
 #[derive(Debug, Clone)]
-pub struct FillBg(pub Palette16);
+pub struct FillBg(pub Colour);
 
 impl Style for FillBg {
     fn assignability(&self) -> Assignability {
         Assignability::CellRowColTable
     }
-}
\ No newline at end of file
+}
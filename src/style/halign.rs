@@ -1,18 +1,13 @@
 use crate::style::{Assignability, Style};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum HAlign {
+    #[default]
     Left,
     Centred,
     Right,
 }
 
-impl Default for HAlign {
-    fn default() -> Self {
-        Self::Left
-    }
-}
-
 impl Style for HAlign {
     fn assignability(&self) -> Assignability {
         Assignability::CellRowColTable
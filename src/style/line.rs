@@ -0,0 +1,20 @@
+/// The style of a border line.
+///
+/// The layout of a table assigns the header line of the renderer's
+/// [`Geometry`](crate::renderer::layout::Geometry), usually [`Line::Bold`], to the borders around
+/// headers, [`Line::Thin`] to the borders between the remaining cells and [`Line::None`] to the
+/// borders of separators,
+/// unless overridden by the [`BorderTop`](super::BorderTop), [`BorderBottom`](super::BorderBottom),
+/// [`BorderLeft`](super::BorderLeft) and [`BorderRight`](super::BorderRight) styles. The
+/// remaining kinds are only ever assigned by those styles; a renderer that cannot draw them
+/// substitutes the nearest line it can.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Line {
+    None,
+    Thin,
+    Bold,
+    Single,
+    Dashed,
+    Heavy,
+    Double,
+}
@@ -0,0 +1,25 @@
+use crate::style::{Assignability, Style};
+
+/// Determines how content that is wider than its column is laid out.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Wraps the content over multiple lines.
+    #[default]
+    Wrap,
+
+    /// Cuts off the content at the end of each line.
+    Truncate,
+
+    /// Cuts off the content at the end of each line, ending it with an ellipsis.
+    TruncateWithEllipsis,
+
+    /// Cuts out the middle of each line, replacing it with an ellipsis. Useful for paths and hashes,
+    /// where both ends carry meaning.
+    TruncateMiddle,
+}
+
+impl Style for Overflow {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
@@ -0,0 +1,13 @@
+use crate::style::{Assignability, Style};
+
+/// The number of blank columns between the content of a cell and its left border. Padding is
+/// filled with the cell's background and is in addition to the [`MinWidth`](crate::style::MinWidth)
+/// and [`MaxWidth`](crate::style::MaxWidth) constraints, which apply to the content alone.
+#[derive(Debug, Clone, Default)]
+pub struct PaddingLeft(pub usize);
+
+impl Style for PaddingLeft {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
@@ -0,0 +1,12 @@
+use crate::style::{Assignability, Style};
+
+/// The number of blank lines between the content of a cell and its top border. Padding is
+/// filled with the cell's background.
+#[derive(Debug, Clone, Default)]
+pub struct PaddingTop(pub usize);
+
+impl Style for PaddingTop {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
@@ -1,8 +1,8 @@
 /// A colour palette optimised for use with ANSI in-band terminal signalling. While designed for
 /// terminal environments, this palette may be mapped to alternate colour spaces.
-
+///
 /// A 16-colour palette.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Palette16 {
     Black,
     Red,
@@ -22,4 +22,30 @@ pub enum Palette16 {
     BrightWhite,
     Default,
     Hidden
-}
\ No newline at end of file
+}
+
+impl Palette16 {
+    /// The RGB components of this colour, per the conventional VGA palette, or `None` for
+    /// [`Palette16::Default`] and [`Palette16::Hidden`].
+    pub fn rgb(&self) -> Option<(u8, u8, u8)> {
+        match self {
+            Palette16::Black => Some((0x00, 0x00, 0x00)),
+            Palette16::Red => Some((0xaa, 0x00, 0x00)),
+            Palette16::Green => Some((0x00, 0xaa, 0x00)),
+            Palette16::Yellow => Some((0xaa, 0x55, 0x00)),
+            Palette16::Blue => Some((0x00, 0x00, 0xaa)),
+            Palette16::Magenta => Some((0xaa, 0x00, 0xaa)),
+            Palette16::Cyan => Some((0x00, 0xaa, 0xaa)),
+            Palette16::White => Some((0xaa, 0xaa, 0xaa)),
+            Palette16::BrightBlack => Some((0x55, 0x55, 0x55)),
+            Palette16::BrightRed => Some((0xff, 0x55, 0x55)),
+            Palette16::BrightGreen => Some((0x55, 0xff, 0x55)),
+            Palette16::BrightYellow => Some((0xff, 0xff, 0x55)),
+            Palette16::BrightBlue => Some((0x55, 0x55, 0xff)),
+            Palette16::BrightMagenta => Some((0xff, 0x55, 0xff)),
+            Palette16::BrightCyan => Some((0x55, 0xff, 0xff)),
+            Palette16::BrightWhite => Some((0xff, 0xff, 0xff)),
+            Palette16::Default | Palette16::Hidden => None,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::style::{Assignability, Style};
+
+/// The number of rows occupied by a cell, including the row in which the cell resides.
+/// The cells in the column that are covered by the span are hidden.
+#[derive(Debug, Clone)]
+pub struct RowSpan(pub usize);
+
+impl Default for RowSpan {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl Style for RowSpan {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellOnly
+    }
+}
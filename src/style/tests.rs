@@ -1,7 +1,11 @@
-use crate::style::{Assignability, Style, Styles};
+use crate::style::{Assignability, Colour, ColourDepth, Palette16, Style, Styles, TextFg};
 
 #[test]
 fn assignability() {
+    assert!(!Assignability::CellOnly.at_col());
+    assert!(!Assignability::CellOnly.at_row());
+    assert!(Assignability::CellOnly.at_cell());
+
     assert!(!Assignability::TableOnly.at_col());
     assert!(!Assignability::TableOnly.at_row());
     assert!(!Assignability::TableOnly.at_cell());
@@ -52,4 +56,57 @@ fn resolve() {
 fn can_clone() {
     let styles = Styles::default().with(SampleStyleOne);
     let _ = styles.clone();
-}
\ No newline at end of file
+}
+
+#[test]
+fn colour_from_palette_16() {
+    assert_eq!(Colour::Palette16(Palette16::Red), TextFg(Palette16::Red.into()).0);
+    assert_eq!(Colour::Rgb(1, 2, 3), TextFg(Colour::Rgb(1, 2, 3)).0);
+}
+
+#[test]
+fn downsample_colour() {
+    #[derive(Debug)]
+    struct Case {
+        input: (Colour, ColourDepth),
+        expect: Colour,
+    }
+
+    for case in [
+        Case {
+            input: (Colour::Rgb(255, 0, 0), ColourDepth::TrueColour),
+            expect: Colour::Rgb(255, 0, 0),
+        },
+        Case {
+            input: (Colour::Rgb(255, 0, 0), ColourDepth::Palette256),
+            expect: Colour::Palette256(196),
+        },
+        Case {
+            input: (Colour::Rgb(100, 100, 100), ColourDepth::Palette256),
+            expect: Colour::Palette256(241),
+        },
+        Case {
+            input: (Colour::Rgb(250, 90, 80), ColourDepth::Palette16),
+            expect: Colour::Palette16(Palette16::BrightRed),
+        },
+        Case {
+            input: (Colour::Palette256(21), ColourDepth::Palette16),
+            expect: Colour::Palette16(Palette16::Blue),
+        },
+        Case {
+            input: (Colour::Palette256(9), ColourDepth::Palette16),
+            expect: Colour::Palette16(Palette16::BrightRed),
+        },
+        Case {
+            input: (Colour::Palette256(21), ColourDepth::Palette256),
+            expect: Colour::Palette256(21),
+        },
+        Case {
+            input: (Palette16::Default.into(), ColourDepth::Palette16),
+            expect: Colour::Palette16(Palette16::Default),
+        },
+    ] {
+        let actual = case.input.0.downsample(case.input.1);
+        assert_eq!(case.expect, actual, "for {:?}", &case);
+    }
+}
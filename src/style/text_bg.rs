@@ -1,10 +1,12 @@
-use crate::style::{Assignability, Palette16, Style};
+use crate::style::{Assignability, Colour, Style};
 
+/// The colour behind the text of a cell or caption, leaving its padding and the remainder of its
+/// lines unpainted; see [`FillBg`](super::FillBg) to paint those too.
 #[derive(Debug, Clone)]
-pub struct TextBg(pub Palette16);
+pub struct TextBg(pub Colour);
 
 impl Style for TextBg {
     fn assignability(&self) -> Assignability {
         Assignability::CellRowColTable
     }
-}
\ No newline at end of file
+}
@@ -1,10 +1,12 @@
-use crate::style::{Assignability, Palette16, Style};
+use crate::style::{Assignability, Colour, Style};
 
+/// The colour of the text of a cell or caption. A [`Palette16`](super::Palette16) colour converts
+/// into a [`Colour`], as in `TextFg(Palette16::Red.into())`.
 #[derive(Debug, Clone)]
-pub struct TextFg(pub Palette16);
+pub struct TextFg(pub Colour);
 
 impl Style for TextFg {
     fn assignability(&self) -> Assignability {
         Assignability::CellRowColTable
     }
-}
\ No newline at end of file
+}
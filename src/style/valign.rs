@@ -0,0 +1,15 @@
+use crate::style::{Assignability, Style};
+
+#[derive(Debug, Clone, Default)]
+pub enum VAlign {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+impl Style for VAlign {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
@@ -1,4 +1,7 @@
-use crate::style::{Separator, Styled, Styles};
+use crate::style::{
+    Banding, ColSpan, Conditional, Header, RowSpan, Separator, Style, Styled, Styles, Subject,
+};
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -10,6 +13,12 @@ pub struct Table {
     styles: Styles,
     cols: Vec<Col>,
     rows: Vec<Row>,
+    title: Option<Caption>,
+    footer: Option<Caption>,
+
+    /// The index of each row among the body rows, which are neither headers nor separators, as
+    /// counted for the table's [`Banding`] style. Maintained as rows are pushed.
+    body_rows: Vec<Option<usize>>,
 }
 
 impl Styled for Table {
@@ -20,7 +29,16 @@ impl Styled for Table {
 
 impl Table {
     pub fn new(styles: Styles, cols: Vec<Col>, rows: Vec<Row>) -> Self {
-        Self { styles, cols, rows }
+        let mut table = Self {
+            styles,
+            cols,
+            rows,
+            title: None,
+            footer: None,
+            body_rows: Vec::new(),
+        };
+        (0..table.rows.len()).for_each(|row| table.index_body_row(row));
+        table
     }
 
     pub fn with_styles(styles: Styles) -> Self {
@@ -48,6 +66,18 @@ impl Table {
         s
     }
 
+    #[must_use]
+    pub fn with_title(mut self, title: impl Into<Caption>) -> Self {
+        self.set_title(title);
+        self
+    }
+
+    #[must_use]
+    pub fn with_footer(mut self, footer: impl Into<Caption>) -> Self {
+        self.set_footer(footer);
+        self
+    }
+
     /// Assigns columns to the table. The number of columns cannot be less (but may exceed)
     /// the number of cells in the widest row.
     ///
@@ -68,6 +98,21 @@ impl Table {
             self.cols.push(Col::new(Styles::default()));
         }
         self.rows.push(row);
+        self.index_body_row(self.rows.len() - 1);
+    }
+
+    /// Records the index of the given row among the body rows, if it is one.
+    fn index_body_row(&mut self, row: usize) {
+        let styles = self.row(row).blended_styles();
+        let body_row = if Header::resolve_or_default(&styles).0
+            || Separator::resolve_or_default(&styles).0
+        {
+            None
+        } else {
+            let preceding = self.body_rows.iter().rev().find_map(|&body_row| body_row);
+            Some(preceding.map_or(0, |body_row| body_row + 1))
+        };
+        self.body_rows.push(body_row);
     }
 
     pub fn push_rows(&mut self, it: impl IntoIterator<Item = Row>) {
@@ -76,6 +121,14 @@ impl Table {
         }
     }
 
+    pub fn set_title(&mut self, title: impl Into<Caption>) {
+        self.title = Some(title.into());
+    }
+
+    pub fn set_footer(&mut self, footer: impl Into<Caption>) {
+        self.footer = Some(footer.into());
+    }
+
     pub fn num_rows(&self) -> usize {
         self.rows.len()
     }
@@ -88,33 +141,95 @@ impl Table {
         self.rows.iter().map(|row| row.1.len()).max().unwrap_or(0)
     }
 
-    pub fn col(&self, col: usize) -> Element<Col> {
+    pub fn col(&self, col: usize) -> Element<'_, Col> {
         let parent_styles = vec![&self.styles];
         let col = self.cols.get(col);
         Element {
             parent_styles,
-            element: col,
+            value: col,
+            position: None,
+            content: None,
+            text: None,
         }
     }
 
-    pub fn row(&self, row_idx: usize) -> Element<Row> {
+    pub fn row(&self, row_idx: usize) -> Element<'_, Row> {
         let parent_styles = vec![&self.styles];
         let row = self.rows.get(row_idx);
         Element {
             parent_styles,
-            element: row,
+            value: row,
+            position: None,
+            content: None,
+            text: None,
+        }
+    }
+
+    /// The caption drawn above the table, if any.
+    pub fn title(&self) -> Element<'_, Caption> {
+        Element {
+            parent_styles: vec![&self.styles],
+            value: self.title.as_ref(),
+            position: None,
+            content: None,
+            text: None,
+        }
+    }
+
+    /// The caption drawn below the table, if any.
+    pub fn footer(&self) -> Element<'_, Caption> {
+        Element {
+            parent_styles: vec![&self.styles],
+            value: self.footer.as_ref(),
+            position: None,
+            content: None,
+            text: None,
         }
     }
 
-    pub fn cell(&self, col_idx: usize, row_idx: usize) -> Element<Cell> {
+    /// Numbers the footnotes attached to the cells of the table in reading order: left to right,
+    /// then top to bottom, starting from 1. Cells hidden by another cell's span are skipped.
+    pub fn footnotes(&self) -> Vec<Footnote> {
+        if self.rows.iter().all(|row| row.1.iter().all(|cell| cell.footnote.is_none())) {
+            // spare resolving the spans of a table without footnotes
+            return Vec::new();
+        }
+        let spans = self.spans();
+        let mut footnotes = Vec::new();
+        for (row, cells) in self.rows.iter().enumerate() {
+            for (col, cell) in cells.1.iter().enumerate() {
+                if let Some(text) = cell.footnote() {
+                    if spans.is_origin(col, row) {
+                        footnotes.push(Footnote {
+                            col,
+                            row,
+                            number: footnotes.len() + 1,
+                            text: text.into(),
+                        });
+                    }
+                }
+            }
+        }
+        footnotes
+    }
+
+    /// The cell at the given position. The shading of its row, as per the table's [`Banding`]
+    /// style, is blended in between the styles of its column and its row.
+    pub fn cell(&self, col_idx: usize, row_idx: usize) -> Element<'_, Cell> {
         let col = self.cols.get(col_idx);
         let row = self.rows.get(row_idx);
-        let mut parent_styles = vec![&self.styles];
+        // the table, column, shading and row styles
+        let mut parent_styles = Vec::with_capacity(4);
+        parent_styles.push(&self.styles);
 
         if let Some(col) = col {
             parent_styles.push(col.styles());
         }
 
+        if let Some(shading) = self.shading(row_idx) {
+            parent_styles.push(shading);
+        }
+
         let cell = match row {
             None => None,
             Some(row) => {
@@ -125,13 +240,134 @@ impl Table {
 
         Element {
             parent_styles,
-            element: cell,
+            value: cell,
+            position: Some((col_idx, row_idx)),
+            content: cell.map(Cell::data),
+            text: None,
         }
     }
 
+    /// The shading styles of the given row, as per the table's [`Banding`] style, or `None` for a
+    /// row outside of a shaded band.
+    fn shading(&self, row: usize) -> Option<&Styles> {
+        let banding = Banding::resolve(&self.styles)?;
+        let body_row = self.body_rows.get(row).copied().flatten()?;
+        banding.is_shaded(body_row).then_some(&banding.styles)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.num_rows() == 0 || self.num_cols() == 0
     }
+
+    /// Resolves the [`ColSpan`] and [`RowSpan`] styles of the cells into a [`Spans`] map.
+    ///
+    /// Cells are visited in reading order. A span is clipped to the bounds of the table and
+    /// to any region already covered by an earlier span, so that spans never overlap.
+    pub fn spans(&self) -> Spans {
+        let num_cols = self.num_cols();
+        let num_rows = self.num_rows();
+        let mut origins = vec![None; num_cols * num_rows];
+        let mut extents = vec![(1, 1); num_cols * num_rows];
+        for row in 0..num_rows {
+            for col in 0..num_cols {
+                if origins[row * num_cols + col].is_some() {
+                    continue;
+                }
+
+                let (col_span, row_span) = match *self.cell(col, row) {
+                    None => (1, 1),
+                    Some(cell) => (
+                        ColSpan::resolve_or_default(cell.styles()).0,
+                        RowSpan::resolve_or_default(cell.styles()).0,
+                    ),
+                };
+                let span_cols = (col..usize::min(col + usize::max(col_span, 1), num_cols))
+                    .take_while(|&c| origins[row * num_cols + c].is_none())
+                    .count();
+                let span_rows = (row..usize::min(row + usize::max(row_span, 1), num_rows))
+                    .take_while(|&r| {
+                        (col..col + span_cols).all(|c| origins[r * num_cols + c].is_none())
+                    })
+                    .count();
+
+                for r in row..row + span_rows {
+                    for c in col..col + span_cols {
+                        origins[r * num_cols + c] = Some((col, row));
+                    }
+                }
+                extents[row * num_cols + col] = (span_cols, span_rows);
+            }
+        }
+
+        Spans {
+            num_cols,
+            origins: origins.into_iter().map(Option::unwrap).collect(),
+            extents,
+        }
+    }
+}
+
+/// Maps every slot in the table's grid to the cell that occupies it, accounting for
+/// cells that span multiple columns or rows.
+#[derive(Clone)]
+pub struct Spans {
+    num_cols: usize,
+    origins: Vec<(usize, usize)>,
+    extents: Vec<(usize, usize)>,
+}
+
+impl Spans {
+    /// The `(col, row)` coordinates of the cell that occupies the given slot. For a slot that
+    /// isn't covered by a span, these are the coordinates of the slot itself.
+    pub fn origin(&self, col: usize, row: usize) -> (usize, usize) {
+        self.origins[row * self.num_cols + col]
+    }
+
+    /// Whether the cell at the given slot is visible; i.e., it isn't covered by another cell's span.
+    pub fn is_origin(&self, col: usize, row: usize) -> bool {
+        self.origin(col, row) == (col, row)
+    }
+
+    /// The `(cols, rows)` occupied by the cell at the given slot. A hidden cell occupies
+    /// no slots.
+    pub fn extent(&self, col: usize, row: usize) -> (usize, usize) {
+        if self.is_origin(col, row) {
+            self.extents[row * self.num_cols + col]
+        } else {
+            (0, 0)
+        }
+    }
+
+    /// Projects the spans onto a window comprising the given columns and rows, each listed in
+    /// ascending order. The slots of the returned spans are indexed by their position within
+    /// the window. A cell whose origin lies outside the window originates in its first slot
+    /// within the window instead, occupying only those of its slots that lie within the window.
+    #[must_use]
+    pub fn window(&self, cols: &[usize], rows: &[usize]) -> Spans {
+        let num_cols = cols.len();
+        let mut origins = Vec::with_capacity(cols.len() * rows.len());
+        let mut extents = vec![(0, 0); cols.len() * rows.len()];
+        for (window_row, &row) in rows.iter().enumerate() {
+            for (window_col, &col) in cols.iter().enumerate() {
+                let (origin_col, origin_row) = self.origin(col, row);
+                let first_col = cols.partition_point(|&c| c < origin_col);
+                let first_row = rows.partition_point(|&r| r < origin_row);
+                origins.push((first_col, first_row));
+                if (first_col, first_row) == (window_col, window_row) {
+                    let (span_cols, span_rows) = self.extent(origin_col, origin_row);
+                    let last_col = cols.partition_point(|&c| c < origin_col + span_cols);
+                    let last_row = rows.partition_point(|&r| r < origin_row + span_rows);
+                    extents[window_row * num_cols + window_col] =
+                        (last_col - first_col, last_row - first_row);
+                }
+            }
+        }
+        Spans {
+            num_cols,
+            origins,
+            extents,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -197,6 +433,7 @@ impl<I> From<I> for Row where I: IntoIterator, I::Item: ToString {
 pub struct Cell {
     styles: Styles,
     data: Content,
+    footnote: Option<String>,
 }
 
 impl Styled for Cell {
@@ -208,12 +445,28 @@ impl Styled for Cell {
 impl Cell {
     pub fn new(styles: Styles, data: Content) -> Self {
         styles.assert_assignability::<Self>(|assignability| assignability.at_cell());
-        Self { styles, data }
+        Self {
+            styles,
+            data,
+            footnote: None,
+        }
+    }
+
+    /// Attaches a note to the cell, which is printed below the table and referenced by a numbered
+    /// marker appended to the content of the cell.
+    #[must_use]
+    pub fn with_footnote(mut self, footnote: impl Into<String>) -> Self {
+        self.footnote = Some(footnote.into());
+        self
     }
 
     pub fn data(&self) -> &Content {
         &self.data
     }
+
+    pub fn footnote(&self) -> Option<&str> {
+        self.footnote.as_deref()
+    }
 }
 
 impl From<Content> for Cell {
@@ -234,6 +487,51 @@ impl<S: ToString> From<S> for Cell {
     }
 }
 
+/// A note attached to the cell at the given column and row, alongside its number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Footnote {
+    pub col: usize,
+    pub row: usize,
+    pub number: usize,
+    pub text: String,
+}
+
+/// A single line of text drawn above or below a table, spanning all of its columns. A caption
+/// accepts the same styles as a cell, such as its alignment and text formatting.
+pub struct Caption {
+    styles: Styles,
+    text: String,
+}
+
+impl Styled for Caption {
+    fn styles(&self) -> &Styles {
+        &self.styles
+    }
+}
+
+impl Caption {
+    /// Creates a caption from the given styles and text.
+    ///
+    /// # Panics
+    /// If the text spans multiple lines, or if the styles cannot be assigned to a cell.
+    pub fn new(styles: Styles, text: impl Into<String>) -> Self {
+        styles.assert_assignability::<Self>(|assignability| assignability.at_cell());
+        let text = text.into();
+        assert!(!text.contains('\n'), "caption cannot span multiple lines");
+        Self { styles, text }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl<S: ToString> From<S> for Caption {
+    fn from(text: S) -> Self {
+        Self::new(Styles::default(), text.to_string())
+    }
+}
+
 pub enum Content {
     Label(String),
     Computed(Box<dyn Fn() -> String>),
@@ -247,6 +545,24 @@ impl<S: ToString> From<S> for Content {
     }
 }
 
+impl Content {
+    /// The text of the content, less any nested tables, against which conditions are evaluated.
+    fn text(&self) -> Cow<'_, str> {
+        match self {
+            Content::Label(s) => Cow::Borrowed(s),
+            Content::Computed(f) => Cow::Owned(f()),
+            Content::Nested(_) => Cow::Borrowed(""),
+            Content::Composite(contents) => {
+                let mut buf = String::new();
+                for content in contents {
+                    buf.push_str(&content.text());
+                }
+                Cow::Owned(buf)
+            }
+        }
+    }
+}
+
 impl From<Table> for Content {
     fn from(table: Table) -> Self {
         Self::Nested(table)
@@ -255,7 +571,10 @@ impl From<Table> for Content {
 
 pub struct Element<'a, T: Styled> {
     parent_styles: Vec<&'a Styles>,
-    element: Option<&'a T>,
+    value: Option<&'a T>,
+    position: Option<(usize, usize)>,
+    content: Option<&'a Content>,
+    text: Option<&'a str>,
 }
 
 impl<'a, T: Styled> Element<'a, T> {
@@ -263,13 +582,38 @@ impl<'a, T: Styled> Element<'a, T> {
         &self.parent_styles
     }
 
+    /// Evaluates the [`Conditional`] rules of a cell against the given text, rather than the text
+    /// extracted from its content. Renderers pass the text of the content as already rendered, so
+    /// that [`Content::Computed`] content is evaluated once.
+    #[must_use]
+    pub fn with_text(mut self, text: &'a str) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    /// Blends the styles of the parents and the element, in increasing order of precedence. For a
+    /// cell, the [`Conditional`] rules at each level are evaluated against its content, and the
+    /// styles of the satisfied rules are blended in after the remaining styles of that level.
     pub fn blended_styles(&self) -> Styles {
         let mut styles = Styles::default();
-        for &s in &self.parent_styles {
+        let mut text = None;
+        let levels = self.parent_styles.iter().copied();
+        for s in levels.chain(self.value.map(Styled::styles)) {
             styles.insert_all(s);
-        }
-        if let Some(element) = self.element {
-            styles.insert_all(element.styles());
+            let Some((col, row)) = self.position else {
+                continue;
+            };
+            if let Some(conditional) = Conditional::resolve(s) {
+                // the text is only extracted once a level turns out to be conditional
+                let text = text.get_or_insert_with(|| match self.text {
+                    Some(text) => Cow::Borrowed(text),
+                    None => self.content.map_or(Cow::Borrowed(""), Content::text),
+                });
+                let subject = Subject { col, row, text };
+                for matching in conditional.matching(&subject) {
+                    styles.insert_all(matching);
+                }
+            }
         }
         styles
     }
@@ -279,7 +623,7 @@ impl<'a, T: Styled> Deref for Element<'a, T> {
     type Target = Option<&'a T>;
 
     fn deref(&self) -> &Self::Target {
-        &self.element
+        &self.value
     }
 }
 
@@ -1,6 +1,12 @@
 use alloc::vec;
-use crate::style::{Bold, BorderFg, Header, Palette16, Styles};
-use crate::table::{Cell, Content, Col, Row};
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use crate::style::{
+    Banding, Bold, BorderFg, ColSpan, Conditional, HAlign, Header, Palette16, RowSpan, Style,
+    Styles, TextFg,
+};
+use crate::table::{Caption, Cell, Content, Col, Footnote, Row, Table};
 
 #[test]
 fn cell_style_assignability_allows() {
@@ -21,7 +27,7 @@ fn row_style_assignability_allows() {
 #[test]
 #[should_panic(expected="cannot assign style stanza::style::border_fg::BorderFg to a stanza::table::Row")]
 fn row_style_assignability_panics() {
-    Row::new(Styles::default().with(BorderFg(Palette16::Blue)), vec![]);
+    Row::new(Styles::default().with(BorderFg(Palette16::Blue.into())), vec![]);
 }
 
 #[test]
@@ -32,5 +38,170 @@ fn col_style_assignability_allows() {
 #[test]
 #[should_panic(expected="cannot assign style stanza::style::border_fg::BorderFg to a stanza::table::Col")]
 fn col_style_assignability_panics() {
-    Col::new(Styles::default().with(BorderFg(Palette16::Blue)));
-}
\ No newline at end of file
+    Col::new(Styles::default().with(BorderFg(Palette16::Blue.into())));
+}
+
+#[test]
+fn spans_clipped_to_table_and_earlier_spans() {
+    let table = Table::default()
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                Cell::new(Styles::default().with(RowSpan(2)), "a".into()),
+                Cell::new(Styles::default().with(ColSpan(5)), "b".into()),
+                "".into(),
+            ],
+        ))
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                "".into(),
+                "c".into(),
+                Cell::new(Styles::default().with(RowSpan(3)), "d".into()),
+            ],
+        ));
+
+    let spans = table.spans();
+    assert_eq!((1, 2), spans.extent(0, 0));
+    assert_eq!((2, 1), spans.extent(1, 0));
+    assert_eq!((0, 0), spans.extent(2, 0));
+    assert_eq!((1, 0), spans.origin(2, 0));
+    assert_eq!((0, 0), spans.origin(0, 1));
+    assert!(!spans.is_origin(0, 1));
+    assert!(spans.is_origin(1, 1));
+    assert_eq!((1, 1), spans.extent(2, 1));
+}
+
+#[test]
+fn spans_window() {
+    // a 4×3 table, with a cell at (1, 0) spanning 3 columns and 2 rows
+    let table = Table::default()
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                "a".into(),
+                Cell::new(Styles::default().with(ColSpan(3)).with(RowSpan(2)), "b".into()),
+            ],
+        ))
+        .with_row(["c"])
+        .with_row(["d", "e", "f", "g"]);
+
+    // columns 0, 2 and 3 of rows 1 and 2
+    let spans = table.spans().window(&[0, 2, 3], &[1, 2]);
+    assert_eq!((1, 1), spans.extent(0, 0));
+    assert_eq!((2, 1), spans.extent(1, 0));
+    assert_eq!((1, 0), spans.origin(2, 0));
+    assert_eq!((0, 0), spans.extent(2, 0));
+    assert_eq!((1, 1), spans.extent(2, 1));
+}
+
+#[test]
+#[should_panic(expected="caption cannot span multiple lines")]
+fn caption_rejects_multiple_lines() {
+    Caption::new(Styles::default(), "first\nsecond");
+}
+
+#[test]
+fn footnotes_in_reading_order() {
+    let table = Table::default()
+        .with_row(Row::new(
+            Styles::default(),
+            vec![
+                Cell::new(Styles::default().with(ColSpan(2)), "a".into()).with_footnote("first"),
+                Cell::from("hidden").with_footnote("skipped"),
+                Cell::from("c").with_footnote("second"),
+            ],
+        ))
+        .with_row(Row::new(
+            Styles::default(),
+            vec![Cell::from("d").with_footnote("third"), "e".into(), "f".into()],
+        ));
+    assert_eq!(
+        vec![footnote(0, 0, 1, "first"), footnote(2, 0, 2, "second"), footnote(0, 1, 3, "third")],
+        table.footnotes()
+    );
+}
+
+fn footnote(col: usize, row: usize, number: usize, text: &str) -> Footnote {
+    Footnote { col, row, number, text: text.to_string() }
+}
+
+#[test]
+fn row_shading_skips_header_and_separator_rows() {
+    let shading = Styles::default().with(Bold(true));
+    let (o, x) = (false, true);
+    let default = banded_table(Banding::new(shading.clone()));
+    assert_eq!(vec![o, o, x, o, o, x, o, x, o], shaded(&default));
+
+    // the shading yields to the styles of a shaded row
+    let overridden = banded_table(Banding::new(shading.clone()))
+        .with_row(Row::new(Styles::default().with(Bold(false)), vec!["c".into()]))
+        .with_row(["d"]);
+    assert_eq!(vec![o, o, x, o, o, x, o, x, o, o, o], shaded(&overridden));
+
+    let grouped = banded_table(Banding::new(shading.clone()).with_rows(2).with_period(3));
+    assert_eq!(vec![o, o, o, o, o, o, x, x, o], shaded(&grouped));
+
+    let unbanded = Table::default().with_row(["a"]).with_row(["b"]);
+    assert_eq!(vec![o, o], shaded(&unbanded));
+}
+
+fn banded_table(banding: Banding) -> Table {
+    Table::with_styles(Styles::default().with(banding))
+        .with_row(Row::new(Styles::default().with(Header(true)), vec!["h".into()]))
+        .with_rows((0..3).map(|_| Row::from(["a"])))
+        .with_row(Row::separator())
+        .with_rows((0..4).map(|_| Row::from(["b"])))
+}
+
+/// Whether the cells of each row blend in the shading, which emboldens them.
+fn shaded(table: &Table) -> Vec<bool> {
+    (0..table.num_rows())
+        .map(|row| Bold::resolve_or_default(&table.cell(0, row).blended_styles()).0)
+        .collect()
+}
+
+#[test]
+fn conditional_styles() {
+    let negative = Conditional::default().with_rule(
+        |subject| subject.text.starts_with('-'),
+        Styles::default().with(TextFg(Palette16::Red.into())).with(Bold(true)),
+    );
+    let first_col = Conditional::default()
+        .with_rule(|subject| subject.col == 0, Styles::default().with(HAlign::Right));
+    let table = Table::with_styles(Styles::default().with(negative))
+        .with_row(["-1", "2"])
+        .with_row(Row::new(
+            Styles::default().with(first_col),
+            vec![
+                "-3".into(),
+                Cell::new(Styles::default().with(Bold(false)), Content::Composite(vec![
+                    "-".into(),
+                    Content::Computed(Box::new(|| "4".into())),
+                ])),
+            ],
+        ))
+        .with_row(Row::new(Styles::default(), vec![Table::default().with_row(["-5"]).into()]));
+
+    assert_eq!((true, true, false), styled(&table, 0, 0));
+    assert_eq!((false, false, false), styled(&table, 1, 0));
+
+    // the row's rules apply alongside the table's
+    assert_eq!((true, true, true), styled(&table, 0, 1));
+
+    // the cell's styles override those contributed by the table's rules
+    assert_eq!((true, false, false), styled(&table, 1, 1));
+
+    // a nested table contributes no text, nor does a missing cell
+    assert_eq!((false, false, false), styled(&table, 0, 2));
+    assert_eq!((false, false, false), styled(&table, 1, 2));
+}
+
+fn styled(table: &Table, col: usize, row: usize) -> (bool, bool, bool) {
+    let styles = table.cell(col, row).blended_styles();
+    (
+        TextFg::resolve(&styles).is_some(),
+        Bold::resolve_or_default(&styles).0,
+        matches!(*HAlign::resolve_or_default(&styles), HAlign::Right),
+    )
+}